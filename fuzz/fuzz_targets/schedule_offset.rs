@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use smoital::schedule::{EquatorialSchedule, HeuristicSchedule, SmonthSchedule};
+
+// Offset computation must never panic, whatever the day index or natural timezone.
+fuzz_target!(|input: (u32, f64)| {
+    let (day, natural_tz) = input;
+
+    let heuristic = HeuristicSchedule::new(2030, natural_tz);
+    heuristic.get_timezone_offset(day);
+
+    EquatorialSchedule::new().get_timezone_offset(day);
+});
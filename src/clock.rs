@@ -1,6 +1,15 @@
-use chrono::{DateTime, Timelike, Utc};
+use chrono::{DateTime, FixedOffset, SubsecRound, Timelike, Utc};
+use std::time::Duration;
+
+use crate::constants::{MARS_TO_EARTH_RATIO, SLIDE_MINUTES, SOL_LENGTH_SECONDS, STANDARD_DAY_MINS};
+use crate::duration::MarsDuration;
 
 /// Display modes for the "Extended" 40 minutes of the Martian day.
+///
+/// Marked `#[non_exhaustive]` so new notations can be added without a
+/// breaking change; match with a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum DisplayMode {
     /// Standard ISO8601 (e.g., 23:20) - ambiguous logic.
     Unoptimized,
@@ -10,43 +19,413 @@ pub enum DisplayMode {
     ExtendedMinutes,
     /// XM notation (e.g., 12:00 XM).
     XM,
+    /// Explicit bonus-time label (e.g., 23:25 (+5 min)).
+    Labeled,
+    /// 12-hour clock with AM/PM (e.g., 11:20:05 PM). The extended window
+    /// continues the last PM hour as extended minutes (e.g., 11:60 PM).
+    TwelveHour,
 }
 
-pub struct SmoitalClock;
+impl DisplayMode {
+    /// Single-character tag used by [`SmoitalClock::format_tagged`].
+    pub fn tag(&self) -> char {
+        match self {
+            DisplayMode::Unoptimized => 'U',
+            DisplayMode::Overflowed => 'O',
+            DisplayMode::ExtendedMinutes => 'E',
+            DisplayMode::XM => 'X',
+            DisplayMode::Labeled => 'L',
+            DisplayMode::TwelveHour => 'T',
+        }
+    }
+
+    /// Inverse of [`DisplayMode::tag`].
+    pub fn from_tag(tag: char) -> Option<Self> {
+        match tag {
+            'U' => Some(DisplayMode::Unoptimized),
+            'O' => Some(DisplayMode::Overflowed),
+            'E' => Some(DisplayMode::ExtendedMinutes),
+            'X' => Some(DisplayMode::XM),
+            'L' => Some(DisplayMode::Labeled),
+            'T' => Some(DisplayMode::TwelveHour),
+            _ => None,
+        }
+    }
+}
+
+/// Renders UTC instants as Smoital civil time.
+///
+/// The associated functions (`SmoitalClock::format` and friends) use the
+/// standard 40-minute extended window. Build a clock with
+/// [`SmoitalClock::with_extended_minutes`] to experiment with other lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SmoitalClock {
+    extended_minutes: u32,
+}
+
+impl Default for SmoitalClock {
+    fn default() -> Self {
+        Self {
+            extended_minutes: SLIDE_MINUTES as u32,
+        }
+    }
+}
 
 impl SmoitalClock {
+    /// A clock whose extended window covers the last `extended_minutes` minutes
+    /// of the hour before midnight (40 by default, i.e. from 23:20). Returns
+    /// `None` if the window is longer than 60 minutes.
+    pub fn with_extended_minutes(extended_minutes: u32) -> Option<Self> {
+        (extended_minutes <= 60).then_some(Self { extended_minutes })
+    }
+
+    /// Length of the extended window in minutes.
+    pub fn extended_minutes(&self) -> u32 {
+        self.extended_minutes
+    }
+
+    /// Like [`SmoitalClock::format`], but using this clock's extended window:
+    /// it opens at `24:00 - extended_minutes` and maps onto `24:00` onwards.
+    pub fn render(&self, time: DateTime<Utc>, mode: DisplayMode) -> String {
+        self.format_local(time.fixed_offset(), "", mode)
+    }
+
     /// Determines the optimized time string for a given UTC instant.
     ///
     /// This handles the "Gap" logic where 23:20 UTC to 23:59 UTC represents
     /// the extra 40 minutes added to a standard day.
     pub fn format(time: DateTime<Utc>, mode: DisplayMode) -> String {
-        let h = time.hour();
-        let m = time.minute();
-        let s = time.second();
+        Self::format_with_offset(time, FixedOffset::east_opt(0).unwrap(), mode)
+    }
+
+    /// Like [`SmoitalClock::format`], but in the local time of `offset` (e.g. the
+    /// day's Smoital offset), so the extended window is detected at 23:20 local.
+    pub fn format_with_offset(
+        time: DateTime<Utc>,
+        offset: FixedOffset,
+        mode: DisplayMode,
+    ) -> String {
+        Self::default().format_local(time.with_timezone(&offset), "", mode)
+    }
+
+    /// Like [`SmoitalClock::format`], with `frac_digits` (up to 9) fractional
+    /// digits on the seconds, e.g. `24:10:05.250`. The time is rounded to that
+    /// precision first, so 23:59:59.9996 with 3 digits rolls over to
+    /// `00:00:00.000`. Zero digits truncates exactly like `format`.
+    pub fn format_with_precision(
+        time: DateTime<Utc>,
+        mode: DisplayMode,
+        frac_digits: u32,
+    ) -> String {
+        let digits = frac_digits.min(9);
+        if digits == 0 {
+            return Self::format(time, mode);
+        }
+
+        let time = time.round_subsecs(digits as u16);
+        // Leap-second nanoseconds (>= 1e9) stay within the current second.
+        let nanos = time.nanosecond().min(999_999_999);
+        let frac = format!(
+            ".{:0width$}",
+            nanos / 10u32.pow(9 - digits),
+            width = digits as usize
+        );
+        Self::default().format_local(time.fixed_offset(), &frac, mode)
+    }
+
+    fn format_local(&self, local: DateTime<FixedOffset>, frac: &str, mode: DisplayMode) -> String {
+        let h = local.hour();
+        let m = local.minute();
+        let s = local.second();
 
-        // Check for the extended period signature (last 40 mins of Earth day)
-        // In a real integration, this requires timezone context, but for the
-        // library's display logic, we detect the standard "slide back" window.
-        let is_extended = h == 23 && m >= 20;
+        // Check for the extended period signature (the last `extended_minutes`
+        // of the local day), i.e. the "slide back" window.
+        let window_start = 60 - self.extended_minutes;
+        let is_extended = h == 23 && m >= window_start;
 
         if !is_extended {
-            return format!("{:02}:{:02}:{:02}", h, m, s);
+            return self.format_civil(h * 60 + m, s, frac, mode);
+        }
+
+        // Map 23:20 -> minute 1440 (the first minute of the extended window)
+        self.format_civil(24 * 60 + (m - window_start), s, frac, mode)
+    }
+
+    /// Like [`SmoitalClock::format`], but when `prev_day_midnight` is set an exact
+    /// midnight renders as the end of the previous day's extended window rather
+    /// than the start of the next day: `24:40:00` (Overflowed), `23:100:00`
+    /// (ExtendedMinutes), `12:40:00 XM` (XM), `23:60:00 (+40 min)` (Labeled) or
+    /// `11:100:00 PM` (TwelveHour). Useful for spans that end exactly on a day
+    /// boundary; [`SmoitalClock::parse`] reads these back as `(24, 0, 0)`.
+    ///
+    /// Returns `None` in `Unoptimized` mode, which has no notation for the end
+    /// of the window that could not be mistaken for another time.
+    pub fn format_with_midnight(
+        time: DateTime<Utc>,
+        mode: DisplayMode,
+        prev_day_midnight: bool,
+    ) -> Option<String> {
+        let is_midnight = time.num_seconds_from_midnight() == 0 && time.nanosecond() == 0;
+        if !(prev_day_midnight && is_midnight) {
+            return Some(Self::format(time, mode));
         }
+        if mode == DisplayMode::Unoptimized {
+            return None;
+        }
+
+        let clock = Self::default();
+        Some(clock.format_civil(24 * 60 + clock.extended_minutes, 0, "", mode))
+    }
+
+    /// Formats Earth time elapsed since the start of a standard day on a clock
+    /// that ticks in Mars seconds, so the displayed seconds advance at the Mars
+    /// rate (one Earth second shows as ~0.973 Mars seconds).
+    ///
+    /// The reading wraps when the 24h 40m standard day (88,800 Earth seconds)
+    /// ends. In Mars seconds that day is only ~86,424 long, so the reading runs
+    /// up to `24:00:24`; the part past `24:00:00` is rendered in the
+    /// extended-window notation of `mode`.
+    pub fn format_mars_scaled(earth_elapsed: Duration, mode: DisplayMode) -> String {
+        let day = MarsDuration::from_earth_seconds((STANDARD_DAY_MINS * 60) as f64);
+        let mars = MarsDuration::from_earth_seconds(earth_elapsed.as_secs_f64());
+        let secs = mars
+            .as_mars_seconds()
+            .rem_euclid(day.as_mars_seconds())
+            .floor() as u32;
+
+        Self::default().format_civil(secs / 60, secs % 60, "", mode)
+    }
+
+    /// Formats a time of sol in Mars Coordinated Time, `HH:MM:SS` in Mars
+    /// hours, minutes and seconds. A sol is exactly 86,400 Mars seconds
+    /// (`SOL_LENGTH_SECONDS / MARS_TO_EARTH_RATIO`), so the reading wraps back
+    /// to `00:00:00` after a full sol; negative offsets count back from the end.
+    /// Non-finite input renders as `00:00:00`.
+    pub fn format_mtc(mars_seconds_into_sol: f64) -> String {
+        let sol_secs = (SOL_LENGTH_SECONDS / MARS_TO_EARTH_RATIO).round();
+        let secs = if mars_seconds_into_sol.is_finite() {
+            // Flooring can land on `sol_secs` for tiny negative inputs.
+            (mars_seconds_into_sol.rem_euclid(sol_secs).floor() as u32) % sol_secs as u32
+        } else {
+            0
+        };
+
+        format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    }
 
+    /// Renders a civil minute-of-day (0..1440 + extended minutes) and second,
+    /// with `frac` (e.g. `.123`, or empty) appended to the seconds. Minutes from
+    /// 1440 onwards fall in the extended window and are mapped according to `mode`.
+    fn format_civil(&self, minute_of_day: u32, s: u32, frac: &str, mode: DisplayMode) -> String {
+        let h = minute_of_day / 60;
+        let m = minute_of_day % 60;
+
+        if mode == DisplayMode::TwelveHour {
+            // The extended window stays in the 11 PM hour: 23:20 -> 11:60 PM.
+            let (h, m) = if minute_of_day < 24 * 60 {
+                (h, m)
+            } else {
+                (23, minute_of_day - 23 * 60)
+            };
+            let suffix = if h < 12 { "AM" } else { "PM" };
+            let h12 = if h % 12 == 0 { 12 } else { h % 12 };
+            return format!("{:02}:{:02}:{:02}{} {}", h12, m, s, frac, suffix);
+        }
+
+        if minute_of_day < 24 * 60 {
+            return format!("{:02}:{:02}:{:02}{}", h, m, s, frac);
+        }
+
+        let extra = minute_of_day - 24 * 60;
+        let window_start = 60 - self.extended_minutes;
         match mode {
-            DisplayMode::Unoptimized => format!("{:02}:{:02}:{:02}", h, m, s),
+            DisplayMode::Unoptimized => format!("23:{:02}:{:02}{}", extra + window_start, s, frac),
             DisplayMode::Overflowed => {
                 // Map 23:20 -> 24:00
-                format!("24:{:02}:{:02}", m - 20, s)
+                format!("24:{:02}:{:02}{}", extra, s, frac)
             }
             DisplayMode::ExtendedMinutes => {
                 // Map 23:20 -> 23:60
-                format!("23:{:02}:{:02}", m + 40, s)
+                format!("23:{:02}:{:02}{}", extra + 60, s, frac)
             }
             DisplayMode::XM => {
                 // Map 23:20 -> 12:00 XM
-                format!("12:{:02}:{:02} XM", m - 20, s)
+                format!("12:{:02}:{:02}{} XM", extra, s, frac)
+            }
+            DisplayMode::Labeled => {
+                // Map 23:20 -> 23:20 (+0 min)
+                format!(
+                    "23:{:02}:{:02}{} (+{} min)",
+                    extra + window_start,
+                    s,
+                    frac,
+                    extra
+                )
             }
+            DisplayMode::TwelveHour => unreachable!("handled above"),
         }
     }
+
+    /// Hour, minute and second hand angles in degrees (clockwise from the top)
+    /// for an analog clock showing `sol_seconds` of civil time since midnight.
+    ///
+    /// Dial convention: a single 24h 40m face, so the hour hand makes one full
+    /// revolution per standard day (1480 minutes). The minute and second hands
+    /// keep their usual 60-minute and 60-second revolutions.
+    ///
+    /// A day is 24⅔ minute-hand revolutions, so at the 24h 40m wrap the minute
+    /// hand jumps from just under 240° back to 0° (the hour and second hands
+    /// stay continuous). Animate that step rather than sweeping backwards.
+    pub fn hand_angles(sol_seconds: f64) -> (f64, f64, f64) {
+        let day_secs = (STANDARD_DAY_MINS * 60) as f64;
+        let t = sol_seconds.rem_euclid(day_secs);
+
+        let hour = t / day_secs * 360.0;
+        let minute = (t % 3600.0) / 3600.0 * 360.0;
+        let second = (t % 60.0) / 60.0 * 360.0;
+        (hour, minute, second)
+    }
+
+    /// Formats the time like [`SmoitalClock::format`] and appends the mode's
+    /// single-character tag (`U`/`O`/`E`/`X`/`L`/`T`), e.g. `24:10:00 O`.
+    pub fn format_tagged(time: DateTime<Utc>, mode: DisplayMode) -> String {
+        format!("{} {}", Self::format(time, mode), mode.tag())
+    }
+
+    /// Parses a string produced by [`SmoitalClock::format_tagged`], inferring the
+    /// display mode from its tag. Returns the mode and the underlying UTC
+    /// hour/minute/second.
+    pub fn parse_tagged(s: &str) -> Option<(DisplayMode, u32, u32, u32)> {
+        let (body, tag) = s.rsplit_once(' ')?;
+        let mut chars = tag.chars();
+        let mode = DisplayMode::from_tag(chars.next()?)?;
+        if chars.next().is_some() {
+            return None;
+        }
+
+        let (h, m, s) = Self::parse(body, mode)?;
+        Some((mode, h, m, s))
+    }
+
+    /// Parses a string produced by [`SmoitalClock::format`] in `mode`, inverting
+    /// the extended-window mapping back to UTC hour/minute/second (so `24:10:00`
+    /// in `Overflowed` mode yields `(23, 30, 0)`). The end-of-day forms from
+    /// [`SmoitalClock::format_with_midnight`] yield `(24, 0, 0)`.
+    ///
+    /// Accepts `HH:MM`, `HH:MM:SS` and `HH:MM:SS.fff`; seconds default to 0 and
+    /// fractions are dropped.
+    pub fn parse(s: &str, mode: DisplayMode) -> Option<(u32, u32, u32)> {
+        let (s, bonus) = match s
+            .strip_suffix(" min)")
+            .and_then(|rest| rest.rsplit_once(" (+"))
+        {
+            Some((time, minutes)) => (time, Some(Self::parse_digits(minutes)?)),
+            None => (s, None),
+        };
+        let (s, meridiem) = match (s.strip_suffix(" AM"), s.strip_suffix(" PM")) {
+            (Some(body), _) => (body, Some(0)),
+            (_, Some(body)) => (body, Some(12)),
+            _ => (s, None),
+        };
+        let (body, is_xm) = match s.strip_suffix(" XM") {
+            Some(body) => (body, true),
+            None => (s, false),
+        };
+
+        // Accept `HH:MM`, `HH:MM:SS` and `HH:MM:SS.fff`; seconds default to 0
+        // and any fraction is dropped.
+        let mut fields = body.split(':');
+        let h: u32 = Self::parse_digits(fields.next()?)?;
+        let m: u32 = Self::parse_digits(fields.next()?)?;
+        let s: u32 = match fields.next() {
+            Some(field) => {
+                let (whole, frac) = field.split_once('.').unwrap_or((field, "0"));
+                Self::parse_digits(frac)?;
+                Self::parse_digits(whole)?
+            }
+            None => 0,
+        };
+        if fields.next().is_some() || s >= 60 {
+            return None;
+        }
+
+        // The end of the extended window, as written by `format_with_midnight`.
+        let end_of_day = Some((24, 0, 0)).filter(|_| s == 0);
+
+        if mode == DisplayMode::TwelveHour {
+            if !(1..=12).contains(&h) {
+                return None;
+            }
+            let h = h % 12 + meridiem?;
+            return match m {
+                0..60 => Some((h, m, s)),
+                60..100 if h == 23 => Some((23, m - 40, s)),
+                100 if h == 23 => end_of_day,
+                _ => None,
+            };
+        }
+
+        match mode {
+            DisplayMode::XM if is_xm => match m {
+                0..40 if h == 12 => Some((23, m + 20, s)),
+                40 if h == 12 => end_of_day,
+                _ => None,
+            },
+            DisplayMode::Overflowed if h == 24 => match m {
+                0..40 => Some((23, m + 20, s)),
+                40 => end_of_day,
+                _ => None,
+            },
+            DisplayMode::ExtendedMinutes if h == 23 && m >= 60 => match m {
+                60..100 => Some((23, m - 40, s)),
+                100 => end_of_day,
+                _ => None,
+            },
+            DisplayMode::Labeled if bonus.is_some() => match m {
+                _ if h != 23 || m < 20 || bonus != Some(m - 20) => None,
+                20..60 => Some((h, m, s)),
+                60 => end_of_day,
+                _ => None,
+            },
+            _ if is_xm || bonus.is_some() || meridiem.is_some() => None,
+            _ => (h < 24 && m < 60).then_some((h, m, s)),
+        }
+    }
+
+    fn parse_digits(field: &str) -> Option<u32> {
+        if field.is_empty() || !field.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        field.parse().ok()
+    }
+}
+
+/// Precomputed labels for every civil minute of a standard sol, for clocks
+/// that tick too often to format a string each time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolClockCache {
+    labels: Vec<String>,
+}
+
+impl SolClockCache {
+    /// Renders all 1480 minutes of a standard day in `mode`.
+    pub fn new(mode: DisplayMode) -> Self {
+        let labels = (0..STANDARD_DAY_MINS as u32)
+            .map(|minute| SmoitalClock::default().format_civil(minute, 0, "", mode))
+            .collect();
+        Self { labels }
+    }
+
+    /// The label for the start (second 0) of civil `minute`, counted from local
+    /// midnight across the whole 24h 40m day: minutes below 1440 read as plain
+    /// clock times (1400 is `23:20`) and 1440 onwards as the extended window
+    /// in the cache's mode. Minutes wrap at 24h 40m.
+    ///
+    /// This differs from [`SmoitalClock::format`], which relabels 23:20–23:59
+    /// UTC as the extended window: up to 23:19 the labels agree, minutes
+    /// 1400–1439 never come out of `format`, and from 1440 on the label for
+    /// `minute` is what `format` shows 40 minutes earlier in UTC.
+    pub fn label_for_minute(&self, minute: u32) -> &str {
+        &self.labels[minute as usize % self.labels.len()]
+    }
 }
@@ -1,3 +1,5 @@
+use chrono::NaiveDate;
+
 /// The exact ratio of Mars Seconds to Earth Seconds.
 /// Derived from 24h 39m 35s 244ms / 86400s.
 /// Ratio = 1.02749125.
@@ -6,12 +8,43 @@ pub const MARS_TO_EARTH_RATIO: f64 = 1.02749125;
 /// Length of a Martian Sol in Earth seconds (88,775.244 seconds).
 pub const SOL_LENGTH_SECONDS: f64 = 88_775.244;
 
+/// Length of a Martian (tropical) year in sols, from one Ls 0 to the next.
+/// Longer than a Smoital calendar year, which only has 655 dated days.
+pub const MARS_YEAR_SOLS: f64 = 668.5991;
+
+/// Smoital year anchored at [`SMOITAL_EPOCH`].
+pub const SMOITAL_EPOCH_YEAR: i32 = 1;
+
+/// Gregorian date on which day 0 of [`SMOITAL_EPOCH_YEAR`] begins (00:00 UTC).
+/// Matches the start of Mars Year 1 in the Clancy et al. numbering. Later
+/// years follow on directly, one calendar year (655 sols) apart, so they
+/// start earlier than the Clancy years, which are [`MARS_YEAR_SOLS`] long.
+pub const SMOITAL_EPOCH: NaiveDate = NaiveDate::from_ymd_opt(1955, 4, 11).unwrap();
+
 /// Standard Day length in minutes (24h 40m).
 pub const STANDARD_DAY_MINS: i64 = 24 * 60 + 40;
 
 /// Smol Day length in minutes (24h 00m).
 pub const SMOL_DAY_MINS: i64 = 24 * 60;
 
+/// Daily backward slide of the timezone offset in minutes (Standard minus Smol day).
+pub const SLIDE_MINUTES: i32 = 40;
+
+/// Number of Smonths in a standard Martian year.
+pub const SMONTHS_PER_YEAR: u32 = 18;
+
+/// Number of 40-minute Smol Day corrections needed over `target_civil_minutes`
+/// of Earth-clock time.
+///
+/// Each standard day (24h 40m) runs ~24.7 seconds longer than a sol, so the
+/// civil clock drifts ahead by that much per sol until Smol Days pull it back.
+pub fn required_smol_days(target_civil_minutes: i64) -> u32 {
+    let sol_minutes = SOL_LENGTH_SECONDS / 60.0;
+    let sols = target_civil_minutes as f64 / sol_minutes;
+    let drift_minutes = sols * (STANDARD_DAY_MINS as f64 - sol_minutes);
+    (drift_minutes / SLIDE_MINUTES as f64).round() as u32
+}
+
 // Constants for Heuristic Algorithm
 pub const C1_SECONDS: f64 = 85.0;
 pub const C2: f64 = 4.51;
@@ -1,17 +1,104 @@
-use chrono::FixedOffset;
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{constants::SMONTHS_PER_YEAR, schedule::SmonthSchedule, year::SmoitalYear};
 
 /// Represents a Date in the Smoital System.
 ///
 /// Defined by Year, Smonth (intercalary month), and Day-of-Smonth.
 /// This structure simplifies the handling of the 36/37 day months.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Dates order chronologically by year, then Smonth, then day. Out-of-range
+/// fields are not special-cased and simply compare numerically.
+///
+/// With the `serde` feature, dates (de)serialize as a struct,
+/// `{"year":2030,"smonth":6,"day":37}`, and deserialization rejects
+/// out-of-range Smonths and days. Use the `serde_str` module for the string form.
+///
+/// Prefer [`SmoitalDate::new`] over a struct literal: it rejects impossible
+/// dates such as day 0. The fields stay public for compatibility.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "SmoitalDateFields")
+)]
 pub struct SmoitalDate {
     pub year: i32,
     pub smonth: u32,
     pub day: u32, // 1-37
 }
 
+/// The word used to mark Smol Days in [`SmoitalDate::format_localized`],
+/// e.g. `"kurz"` for German. Defaults to `"smol"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmolLabel(pub String);
+
+impl Default for SmolLabel {
+    fn default() -> Self {
+        SmolLabel("smol".to_string())
+    }
+}
+
+/// Error returned when parsing a [`SmoitalDate`] from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmoitalDateError {
+    /// The string is not of the form `YYYY-SMM-DD`.
+    InvalidFormat,
+    /// A field is not a valid number.
+    InvalidNumber,
+    /// The Smonth is outside 0..=17.
+    SmonthOutOfRange(u32),
+    /// The day is outside 1..=37.
+    DayOutOfRange(u32),
+}
+
+impl fmt::Display for SmoitalDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SmoitalDateError::InvalidFormat => write!(f, "expected a date like 2030-S06-37"),
+            SmoitalDateError::InvalidNumber => write!(f, "date field is not a number"),
+            SmoitalDateError::SmonthOutOfRange(smonth) => {
+                write!(f, "smonth {smonth} is out of range")
+            }
+            SmoitalDateError::DayOutOfRange(day) => write!(f, "day {day} is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for SmoitalDateError {}
+
 impl SmoitalDate {
+    /// Checked constructor: the Smonth must be in 0..=17 and the day in 1..=37.
+    ///
+    /// Whether day 37 exists depends on the schedule; use
+    /// [`SmoitalYear::day_of_year`] to check a date against a specific year.
+    pub fn new(year: i32, smonth: u32, day: u32) -> Result<SmoitalDate, SmoitalDateError> {
+        if smonth >= SMONTHS_PER_YEAR {
+            return Err(SmoitalDateError::SmonthOutOfRange(smonth));
+        }
+        if !(1..=37).contains(&day) {
+            return Err(SmoitalDateError::DayOutOfRange(day));
+        }
+
+        Ok(SmoitalDate { year, smonth, day })
+    }
+
+    /// The Smoital date containing a UTC instant (e.g. `Utc::now()`).
+    ///
+    /// `epoch` is the Gregorian date on which day 0 of `year` begins.
+    /// Instants past the end of the year roll into the following years (see
+    /// [`SmoitalYear::date_from_day`]); instants before the epoch return `None`.
+    pub fn from_utc<S: SmonthSchedule>(
+        utc: DateTime<Utc>,
+        epoch: NaiveDate,
+        year: &SmoitalYear<S>,
+    ) -> Option<SmoitalDate> {
+        year.day_from_utc(utc, epoch)
+            .map(|day| year.date_from_day(day))
+    }
+
     /// Calculate the UTC offset for this date using the standard formula.
     /// UTC-Offset = 760 - 40*D.
     ///
@@ -29,7 +116,170 @@ impl SmoitalDate {
 
     /// Helper to identify if this is a "Smol Day" (Shortened Day).
     /// In Smoital, Smol days are *always* the 37th day.
+    ///
+    /// Note: This only inspects the day field. Day 37 of a 36-day Smonth is an
+    /// invalid date rather than a Smol Day; use [`SmoitalDate::is_smol_day_in`]
+    /// to check against a schedule.
     pub fn is_smol_day(&self) -> bool {
         self.day == 37
     }
+
+    /// Returns true only if this is day 37 of a Smonth that `schedule` defines
+    /// as 37 days long.
+    pub fn is_smol_day_in(&self, schedule: &impl SmonthSchedule) -> bool {
+        self.day == 37 && schedule.get_smonth_length(self.smonth) == 37
+    }
+
+    /// The canonical `Display` form, followed by `(label)` if this is a Smol
+    /// Day under `schedule`, e.g. `2030-S06-37 (kurz)`.
+    pub fn format_localized(&self, schedule: &impl SmonthSchedule, label: &SmolLabel) -> String {
+        if self.is_smol_day_in(schedule) {
+            format!("{self} ({})", label.0)
+        } else {
+            self.to_string()
+        }
+    }
+
+    /// Returns true if both dates fall on the same day-of-year under `schedule`,
+    /// ignoring the `year` field. Useful for aligning anniversaries across years.
+    pub fn same_day_of_year(&self, other: &SmoitalDate, schedule: &impl SmonthSchedule) -> bool {
+        match (
+            schedule.day_of_year(self.smonth, self.day),
+            schedule.day_of_year(other.smonth, other.day),
+        ) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// Pack into a single `u64`: `year` in the high 32 bits, then `smonth` and
+    /// `day` as one byte each. Handy as a database key or wire format.
+    ///
+    /// Returns `None` if the Smonth is out of range or the day is not in
+    /// 1..=37, the same fields [`SmoitalDate::from_packed`] rejects.
+    pub fn to_packed(&self) -> Option<u64> {
+        if self.smonth >= SMONTHS_PER_YEAR || !(1..=37).contains(&self.day) {
+            return None;
+        }
+
+        Some(
+            ((self.year as u32 as u64) << 32) | (u64::from(self.smonth) << 8) | u64::from(self.day),
+        )
+    }
+
+    /// Inverse of [`SmoitalDate::to_packed`]. Returns `None` if the unused bits
+    /// are set, the Smonth is out of range, or the day is not in 1..=37.
+    pub fn from_packed(packed: u64) -> Option<SmoitalDate> {
+        let year = (packed >> 32) as u32 as i32;
+        let smonth = ((packed >> 8) & 0xFF) as u32;
+        let day = (packed & 0xFF) as u32;
+
+        if (packed >> 16) & 0xFFFF != 0 || smonth >= SMONTHS_PER_YEAR || !(1..=37).contains(&day) {
+            return None;
+        }
+
+        Some(SmoitalDate { year, smonth, day })
+    }
+}
+
+impl fmt::Display for SmoitalDate {
+    /// Canonical `YYYY-SMM-DD` form, e.g. `2030-S06-37`; the inverse of
+    /// [`SmoitalDate::from_str`]. Negative years keep their sign (`-0012-S00-01`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.year < 0 { "-" } else { "" };
+        write!(
+            f,
+            "{sign}{:04}-S{:02}-{:02}",
+            self.year.unsigned_abs(),
+            self.smonth,
+            self.day
+        )
+    }
+}
+
+impl FromStr for SmoitalDate {
+    type Err = SmoitalDateError;
+
+    /// Parses the canonical `YYYY-SMM-DD` form, e.g. `2030-S06-37` or
+    /// `-12-S00-01`. Leading zeros are accepted in every field.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        let mut fields = rest.split('-');
+        let (Some(year), Some(smonth), Some(day), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(SmoitalDateError::InvalidFormat);
+        };
+        let smonth = smonth
+            .strip_prefix('S')
+            .ok_or(SmoitalDateError::InvalidFormat)?;
+
+        let year = parse_field::<i64>(year)?;
+        let year = i32::try_from(if negative { -year } else { year })
+            .map_err(|_| SmoitalDateError::InvalidNumber)?;
+        let smonth = parse_field::<u32>(smonth)?;
+        let day = parse_field::<u32>(day)?;
+
+        SmoitalDate::new(year, smonth, day)
+    }
+}
+
+/// Parses an unsigned run of ASCII digits.
+fn parse_field<T: FromStr>(field: &str) -> Result<T, SmoitalDateError> {
+    if field.is_empty() || !field.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(SmoitalDateError::InvalidNumber);
+    }
+    field.parse().map_err(|_| SmoitalDateError::InvalidNumber)
+}
+
+/// Unvalidated mirror of [`SmoitalDate`], deserialized before range checks.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SmoitalDateFields {
+    year: i32,
+    smonth: u32,
+    day: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SmoitalDateFields> for SmoitalDate {
+    type Error = SmoitalDateError;
+
+    fn try_from(fields: SmoitalDateFields) -> Result<Self, Self::Error> {
+        SmoitalDate::new(fields.year, fields.smonth, fields.day)
+    }
+}
+
+/// (De)serializes a [`SmoitalDate`] as its `Display` string (`"2030-S06-37"`)
+/// instead of a struct:
+///
+/// ```ignore
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Event {
+///     #[serde(with = "smoital::date::serde_str")]
+///     date: SmoitalDate,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_str {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    use super::SmoitalDate;
+
+    pub fn serialize<S: Serializer>(date: &SmoitalDate, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(date)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<SmoitalDate, D::Error> {
+        // Owned, so inputs that cannot lend a borrowed `&str` (e.g.
+        // `serde_json::from_value`, `from_reader`) also work.
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
 }
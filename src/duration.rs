@@ -1,16 +1,189 @@
-use crate::constants::MARS_TO_EARTH_RATIO;
+use crate::constants::{MARS_TO_EARTH_RATIO, SLIDE_MINUTES, SOL_LENGTH_SECONDS};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::str::FromStr;
 use std::time::Duration;
 
 /// Represents a duration measured in Martian time units.
 ///
 /// Useful for precise conversion between Earth seconds and Mars seconds,
 /// which is necessary because 1 Mars second = 1.02749125 Earth seconds.
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+///
+/// Durations are signed: subtracting a later instant from an earlier one gives
+/// a negative value. Conversions to the unsigned `std::time::Duration` fail for
+/// those instead of panicking.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
 pub struct MarsDuration {
     mars_seconds: f64,
 }
 
+impl fmt::Debug for MarsDuration {
+    /// Shows both units, e.g. `MarsDuration(123.456 ms_s ≈ 126.85 es)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "MarsDuration({} ms_s ≈ {:.2} es)",
+            self.mars_seconds,
+            self.as_earth_seconds()
+        )
+    }
+}
+
+impl fmt::Display for MarsDuration {
+    /// Mars seconds with an `ms_s` (Mars-second) suffix, e.g. `123.456ms_s`,
+    /// matching `Debug`. Honors a requested precision, so `{:.2}` gives
+    /// `123.46ms_s`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}ms_s", precision, self.mars_seconds),
+            None => write!(f, "{}ms_s", self.mars_seconds),
+        }
+    }
+}
+
+impl FromStr for MarsDuration {
+    type Err = DurationError;
+
+    /// Parses a number with an explicit unit: `ms_s` for Mars seconds, `es` for
+    /// Earth seconds (e.g. `2.5ms_s`, `88775.244es`). Bare numbers are rejected
+    /// as ambiguous, and so is `ms`, which would read as milliseconds.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (value, from_unit): (&str, fn(f64) -> MarsDuration) =
+            if let Some(value) = s.strip_suffix("ms_s") {
+                (value, MarsDuration::from_mars_seconds)
+            } else if let Some(value) = s.strip_suffix("es") {
+                (value, MarsDuration::from_earth_seconds)
+            } else {
+                return Err(DurationError::InvalidFormat);
+            };
+
+        let secs: f64 = value.parse().map_err(|_| DurationError::InvalidFormat)?;
+        if !secs.is_finite() {
+            return Err(DurationError::NonFinite);
+        }
+        Ok(from_unit(secs))
+    }
+}
+
+impl Sum for MarsDuration {
+    fn sum<I: Iterator<Item = MarsDuration>>(iter: I) -> MarsDuration {
+        Self::from_mars_seconds(iter.map(|d| d.mars_seconds).sum())
+    }
+}
+
+impl<'a> Sum<&'a MarsDuration> for MarsDuration {
+    fn sum<I: Iterator<Item = &'a MarsDuration>>(iter: I) -> MarsDuration {
+        iter.copied().sum()
+    }
+}
+
+/// A bare `f64` converts as **Mars seconds**, exactly like
+/// [`MarsDuration::from_mars_seconds`]. Use [`MarsDuration::from_earth_seconds`]
+/// for Earth-second readings.
+impl From<f64> for MarsDuration {
+    fn from(mars_seconds: f64) -> Self {
+        Self::from_mars_seconds(mars_seconds)
+    }
+}
+
+/// Yields **Mars seconds**, like [`MarsDuration::as_mars_seconds`].
+impl From<MarsDuration> for f64 {
+    fn from(duration: MarsDuration) -> Self {
+        duration.as_mars_seconds()
+    }
+}
+
+impl Add for MarsDuration {
+    type Output = MarsDuration;
+
+    fn add(self, rhs: MarsDuration) -> MarsDuration {
+        Self::from_mars_seconds(self.mars_seconds + rhs.mars_seconds)
+    }
+}
+
+impl AddAssign for MarsDuration {
+    fn add_assign(&mut self, rhs: MarsDuration) {
+        self.mars_seconds += rhs.mars_seconds;
+    }
+}
+
+/// May go negative; see [`MarsDuration::sub_clamped_zero`] to absorb float noise.
+impl Sub for MarsDuration {
+    type Output = MarsDuration;
+
+    fn sub(self, rhs: MarsDuration) -> MarsDuration {
+        Self::from_mars_seconds(self.mars_seconds - rhs.mars_seconds)
+    }
+}
+
+impl SubAssign for MarsDuration {
+    fn sub_assign(&mut self, rhs: MarsDuration) {
+        self.mars_seconds -= rhs.mars_seconds;
+    }
+}
+
+impl Mul<f64> for MarsDuration {
+    type Output = MarsDuration;
+
+    fn mul(self, rhs: f64) -> MarsDuration {
+        Self::from_mars_seconds(self.mars_seconds * rhs)
+    }
+}
+
+impl MulAssign<f64> for MarsDuration {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.mars_seconds *= rhs;
+    }
+}
+
+impl Div<f64> for MarsDuration {
+    type Output = MarsDuration;
+
+    fn div(self, rhs: f64) -> MarsDuration {
+        Self::from_mars_seconds(self.mars_seconds / rhs)
+    }
+}
+
+impl DivAssign<f64> for MarsDuration {
+    fn div_assign(&mut self, rhs: f64) {
+        self.mars_seconds /= rhs;
+    }
+}
+
+/// Reasons a `MarsDuration` cannot be represented as a standard Rust `Duration`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationError {
+    /// The duration is negative; `std::time::Duration` is unsigned.
+    Negative,
+    /// The duration is NaN or infinite.
+    NonFinite,
+    /// The duration exceeds the range of `std::time::Duration`.
+    Overflow,
+    /// The input string is not a valid duration.
+    InvalidFormat,
+}
+
+impl fmt::Display for DurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationError::Negative => write!(f, "duration is negative"),
+            DurationError::NonFinite => write!(f, "duration is not finite"),
+            DurationError::Overflow => write!(f, "duration overflows std::time::Duration"),
+            DurationError::InvalidFormat => write!(f, "invalid duration format"),
+        }
+    }
+}
+
+impl std::error::Error for DurationError {}
+
 impl MarsDuration {
+    /// A duration of zero length.
+    pub const ZERO: MarsDuration = MarsDuration { mars_seconds: 0.0 };
+
     /// Create from Martian seconds.
     pub fn from_mars_seconds(secs: f64) -> Self {
         Self { mars_seconds: secs }
@@ -23,6 +196,138 @@ impl MarsDuration {
         }
     }
 
+    /// Converts a slice of Earth-second readings to Mars seconds in one tight,
+    /// vectorizable loop. Panics if `out` is not the same length as `earth`.
+    pub fn batch_from_earth_seconds(earth: &[f64], out: &mut [f64]) {
+        assert_eq!(earth.len(), out.len(), "slices must have equal length");
+        for (mars, &earth_secs) in out.iter_mut().zip(earth) {
+            *mars = earth_secs / MARS_TO_EARTH_RATIO;
+        }
+    }
+
+    /// Inverse of [`MarsDuration::batch_from_earth_seconds`]: Mars seconds to
+    /// Earth seconds. Panics if `out` is not the same length as `mars`.
+    pub fn batch_to_earth_seconds(mars: &[f64], out: &mut [f64]) {
+        assert_eq!(mars.len(), out.len(), "slices must have equal length");
+        for (earth, &mars_secs) in out.iter_mut().zip(mars) {
+            *earth = mars_secs * MARS_TO_EARTH_RATIO;
+        }
+    }
+
+    /// Parse a bare number (e.g. a CSV field) as Earth seconds.
+    ///
+    /// Plain numbers are always Earth seconds here, never Mars seconds.
+    pub fn try_from_earth_str(s: &str) -> Result<MarsDuration, DurationError> {
+        let earth_secs: f64 = s.trim().parse().map_err(|_| DurationError::InvalidFormat)?;
+        if !earth_secs.is_finite() {
+            return Err(DurationError::NonFinite);
+        }
+        Ok(Self::from_earth_seconds(earth_secs))
+    }
+
+    /// Create from a number of sols.
+    ///
+    /// A sol is one physical Martian day: `SOL_LENGTH_SECONDS` (88,775.244) Earth
+    /// seconds, which is exactly 86,400 Mars seconds. It is not the civil day,
+    /// which lasts 24h 40m (or 24h 00m on a Smol Day) of Earth-clock time.
+    pub fn from_sols(sols: f64) -> Self {
+        Self::from_earth_seconds(sols * SOL_LENGTH_SECONDS)
+    }
+
+    /// Get the value in sols, as defined for [`MarsDuration::from_sols`].
+    pub fn as_sols(&self) -> f64 {
+        self.as_earth_seconds() / SOL_LENGTH_SECONDS
+    }
+
+    /// Time of sol: this duration modulo one sol, always in `[0, 1 sol)`.
+    pub fn time_of_sol(&self) -> MarsDuration {
+        let sol = Self::from_sols(1.0).mars_seconds;
+        Self::from_mars_seconds(self.mars_seconds.rem_euclid(sol))
+    }
+
+    /// Decompose into whole sols and the civil `H:M:S` time into the final sol.
+    ///
+    /// The time of sol is measured in Earth-clock seconds, so it can run past
+    /// 24:00. That last stretch is reported the way `DisplayMode::ExtendedMinutes`
+    /// renders it: the hour stays at 23 and the minute runs from 60 to 99.
+    pub fn as_sol_clock_extended(&self) -> (i64, u32, u32, u32) {
+        let sols = self.as_sols().floor();
+        let earth_secs = (self.as_earth_seconds() - sols * SOL_LENGTH_SECONDS).max(0.0);
+
+        let total_secs = earth_secs.floor() as u32;
+        let minutes = total_secs / 60;
+        let s = total_secs % 60;
+
+        let (h, m) = if minutes >= 24 * 60 {
+            (23, 60 + (minutes - 24 * 60))
+        } else {
+            (minutes / 60, minutes % 60)
+        };
+
+        (sols as i64, h, m, s)
+    }
+
+    /// Mission Elapsed Time as `SSS:HH:MM:SS`, with the sol count zero-padded to
+    /// `sol_digits`. Negative (pre-launch) durations are prefixed with `-`.
+    ///
+    /// Hours, minutes and seconds are Mars units, so every sol has exactly 24 hours.
+    pub fn format_met(&self, sol_digits: usize) -> String {
+        let sign = if self.mars_seconds < 0.0 { "-" } else { "" };
+        let total = self.mars_seconds.abs().floor() as u64;
+        let sols = total / 86_400;
+        let h = (total % 86_400) / 3600;
+        let m = (total % 3600) / 60;
+        let s = total % 60;
+        format!("{sign}{sols:0sol_digits$}:{h:02}:{m:02}:{s:02}")
+    }
+
+    /// Parses a Mission Elapsed Time string written by
+    /// [`MarsDuration::format_met`], e.g. `002:05:12:33` or `-000:00:01:30`.
+    ///
+    /// Fields are Mars units, so hours must be below 24 and minutes and seconds
+    /// below 60. The sol field may have any number of digits.
+    pub fn parse_met(s: &str) -> Result<MarsDuration, DurationError> {
+        let (negative, body) = match s.strip_prefix('-') {
+            Some(body) => (true, body),
+            None => (false, s),
+        };
+
+        let fields: Vec<u64> = body
+            .split(':')
+            .map(|field| {
+                if field.is_empty() || !field.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(DurationError::InvalidFormat);
+                }
+                field.parse().map_err(|_| DurationError::InvalidFormat)
+            })
+            .collect::<Result<_, _>>()?;
+        let [sols, h, m, sec] = fields[..] else {
+            return Err(DurationError::InvalidFormat);
+        };
+        if h >= 24 || m >= 60 || sec >= 60 {
+            return Err(DurationError::InvalidFormat);
+        }
+
+        let secs = sols as f64 * 86_400.0 + (h * 3600 + m * 60 + sec) as f64;
+        Ok(Self::from_mars_seconds(if negative { -secs } else { secs }))
+    }
+
+    /// Both forms on one line, e.g. `129600.000s (mars) = 1sol 12:00:00`: the
+    /// raw Mars-second count and the sol clock in Mars units, as in
+    /// [`MarsDuration::format_met`].
+    pub fn format_dual(&self) -> String {
+        let sign = if self.mars_seconds < 0.0 { "-" } else { "" };
+        let total = self.mars_seconds.abs().floor() as u64;
+        format!(
+            "{:.3}s (mars) = {sign}{}sol {:02}:{:02}:{:02}",
+            self.mars_seconds,
+            total / 86_400,
+            (total % 86_400) / 3600,
+            (total % 3600) / 60,
+            total % 60
+        )
+    }
+
     /// Get the value in Martian seconds.
     pub fn as_mars_seconds(&self) -> f64 {
         self.mars_seconds
@@ -33,9 +338,205 @@ impl MarsDuration {
         self.mars_seconds * MARS_TO_EARTH_RATIO
     }
 
-    /// Convert to a standard Rust Duration (Earth time).
-    pub fn to_earth_duration(&self) -> Duration {
+    /// Convert to a standard Rust Duration (Earth time). Returns `None` for
+    /// negative, non-finite or overflowing values; see
+    /// [`MarsDuration::try_into_std`] for the reason.
+    pub fn to_earth_duration(&self) -> Option<Duration> {
+        self.try_into_std().ok()
+    }
+
+    /// Convert to a standard Rust Duration (Earth time), reporting why the
+    /// conversion failed instead of panicking.
+    pub fn try_into_std(&self) -> Result<Duration, DurationError> {
         let earth_secs = self.as_earth_seconds();
-        Duration::from_secs_f64(earth_secs)
+        if !earth_secs.is_finite() {
+            return Err(DurationError::NonFinite);
+        }
+        if earth_secs < 0.0 {
+            return Err(DurationError::Negative);
+        }
+        Duration::try_from_secs_f64(earth_secs).map_err(|_| DurationError::Overflow)
+    }
+
+    /// Convert to a `chrono::Duration` of Earth time, rounded to the nearest
+    /// nanosecond.
+    ///
+    /// Values beyond `chrono::Duration`'s range (about ±292 million years)
+    /// saturate to its minimum or maximum, and NaN converts to zero.
+    pub fn to_chrono_earth(&self) -> chrono::Duration {
+        let earth_secs = self.as_earth_seconds();
+        if earth_secs.is_nan() {
+            return chrono::Duration::zero();
+        }
+
+        let whole = earth_secs.floor();
+        let nanos = ((earth_secs - whole) * 1e9).round() as u32;
+        let (whole, nanos) = if nanos >= 1_000_000_000 {
+            (whole + 1.0, 0)
+        } else {
+            (whole, nanos)
+        };
+
+        let saturated = if earth_secs < 0.0 {
+            chrono::Duration::MIN
+        } else {
+            chrono::Duration::MAX
+        };
+        if !(i64::MIN as f64..i64::MAX as f64).contains(&whole) {
+            return saturated;
+        }
+        chrono::Duration::new(whole as i64, nanos).unwrap_or(saturated)
+    }
+
+    /// Create from a `chrono::Duration` of Earth time, keeping nanosecond precision.
+    pub fn from_chrono_earth(d: chrono::Duration) -> Self {
+        Self::from_earth_seconds(d.num_seconds() as f64 + f64::from(d.subsec_nanos()) * 1e-9)
+    }
+
+    /// Create from integer microseconds of Mars time.
+    pub fn from_mars_microseconds(micros: i64) -> Self {
+        Self::from_mars_seconds(micros as f64 / 1e6)
+    }
+
+    /// Encode as integer microseconds of Mars time for deterministic storage.
+    ///
+    /// Values outside the `i64` range saturate and NaN encodes as 0; use
+    /// [`MarsDuration::try_to_mars_microseconds`] to detect those cases.
+    pub fn to_mars_microseconds(&self) -> i64 {
+        (self.mars_seconds * 1e6).round() as i64
+    }
+
+    /// Encode as integer microseconds of Mars time, failing on non-finite
+    /// values or values outside the `i64` range.
+    pub fn try_to_mars_microseconds(&self) -> Result<i64, DurationError> {
+        let micros = (self.mars_seconds * 1e6).round();
+        if !micros.is_finite() {
+            return Err(DurationError::NonFinite);
+        }
+        // i64::MAX is not exactly representable; 2^63 is the first value past it.
+        if micros < i64::MIN as f64 || micros >= i64::MAX as f64 {
+            return Err(DurationError::Overflow);
+        }
+        Ok(micros as i64)
+    }
+
+    /// `self - other`, snapped to exactly [`MarsDuration::ZERO`] when the result
+    /// is within `tol_mars_seconds` of zero, so float noise does not produce a
+    /// tiny negative duration.
+    pub fn sub_clamped_zero(&self, other: &MarsDuration, tol_mars_seconds: f64) -> MarsDuration {
+        let diff = self.mars_seconds - other.mars_seconds;
+        if diff.abs() <= tol_mars_seconds {
+            Self::ZERO
+        } else {
+            Self::from_mars_seconds(diff)
+        }
+    }
+
+    /// Rounds to the nearest whole number of Smol Day corrections, each
+    /// `SLIDE_MINUTES` (40) minutes of Mars time. Halves round away from zero.
+    pub fn round_to_smol_correction(&self) -> MarsDuration {
+        let correction = f64::from(SLIDE_MINUTES * 60);
+        Self::from_mars_seconds((self.mars_seconds / correction).round() * correction)
+    }
+
+    /// The magnitude of this duration.
+    pub fn abs(&self) -> MarsDuration {
+        Self::from_mars_seconds(self.mars_seconds.abs())
+    }
+
+    /// True if this duration is strictly less than zero (`-0.0` is not negative).
+    pub fn is_negative(&self) -> bool {
+        self.mars_seconds < 0.0
+    }
+
+    /// Total ordering via `f64::total_cmp`, for `sort_by` and `max_by`.
+    ///
+    /// Negative NaN sorts before everything, positive NaN after everything,
+    /// and `-0.0` before `0.0`. `MarsDuration` keeps the IEEE `PartialEq`, so
+    /// use [`FiniteMarsDuration`] when a full `Ord`/`Eq`/`Hash` type is needed.
+    pub fn cmp_total(&self, other: &MarsDuration) -> Ordering {
+        self.mars_seconds.total_cmp(&other.mars_seconds)
+    }
+
+    /// Returns true if the two durations differ by at most `tol_mars_seconds`.
+    pub fn approx_eq(&self, other: &MarsDuration, tol_mars_seconds: f64) -> bool {
+        (self.mars_seconds - other.mars_seconds).abs() <= tol_mars_seconds
+    }
+
+    /// Ratio of this duration to `other`. Returns `None` if `other` is zero.
+    pub fn ratio(&self, other: &MarsDuration) -> Option<f64> {
+        (other.mars_seconds != 0.0).then(|| self.mars_seconds / other.mars_seconds)
+    }
+
+    /// Fraction remaining after exponential decay over this duration,
+    /// i.e. `0.5^(self / half_life)`. Returns `None` for a zero half-life.
+    pub fn scale_exp(&self, half_life: MarsDuration) -> Option<f64> {
+        self.ratio(&half_life)
+            .map(|halvings| 0.5_f64.powf(halvings))
+    }
+
+    /// Weight-normalized average of `(duration, weight)` pairs, computed in Mars seconds.
+    /// Returns `None` for empty input or a zero total weight.
+    pub fn weighted_mean(items: &[(MarsDuration, f64)]) -> Option<MarsDuration> {
+        let total_weight: f64 = items.iter().map(|(_, weight)| weight).sum();
+        if items.is_empty() || total_weight == 0.0 {
+            return None;
+        }
+
+        let weighted_sum: f64 = items
+            .iter()
+            .map(|(duration, weight)| duration.mars_seconds * weight)
+            .sum();
+        Some(Self::from_mars_seconds(weighted_sum / total_weight))
+    }
+}
+
+/// A `MarsDuration` guaranteed to be finite, with a total order.
+///
+/// Implements `Ord`, `Eq` and `Hash`, so it can be used in `BTreeSet`s and
+/// `HashMap`s without NaN sneaking in. Negative zero is normalized to zero.
+#[derive(Debug, Clone, Copy)]
+pub struct FiniteMarsDuration(MarsDuration);
+
+impl FiniteMarsDuration {
+    /// Wraps `duration`, returning `None` if it is NaN or infinite.
+    pub fn new(duration: MarsDuration) -> Option<Self> {
+        let secs = duration.mars_seconds;
+        if !secs.is_finite() {
+            return None;
+        }
+        // Adding 0.0 turns -0.0 into 0.0 so equal values hash equally.
+        Some(Self(MarsDuration::from_mars_seconds(secs + 0.0)))
+    }
+
+    /// The wrapped duration.
+    pub fn get(&self) -> MarsDuration {
+        self.0
+    }
+}
+
+impl PartialEq for FiniteMarsDuration {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for FiniteMarsDuration {}
+
+impl PartialOrd for FiniteMarsDuration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FiniteMarsDuration {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.mars_seconds.total_cmp(&other.0.mars_seconds)
+    }
+}
+
+impl Hash for FiniteMarsDuration {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.mars_seconds.to_bits().hash(state);
     }
 }
@@ -0,0 +1,240 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use std::fmt;
+use std::ops::Sub;
+
+use crate::{
+    constants::SOL_LENGTH_SECONDS,
+    date::SmoitalDate,
+    duration::MarsDuration,
+    schedule::{SmonthSchedule, format_offset},
+    year::SmoitalYear,
+};
+
+/// A point in time in the Smoital System: a date plus the civil time elapsed
+/// since the start of that day.
+///
+/// Civil time is kept in Earth-clock seconds, so a standard day runs from 0 to
+/// 88,800 seconds (24h 40m) while a Smol Day only runs to 86,400 (24h 00m).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmoitalInstant {
+    pub date: SmoitalDate,
+    /// Civil seconds since the start of `date`.
+    pub sol_seconds: f64,
+}
+
+impl SmoitalInstant {
+    pub fn new(date: SmoitalDate, sol_seconds: f64) -> Self {
+        Self { date, sol_seconds }
+    }
+
+    /// The current instant, from `Utc::now()`. See [`SmoitalInstant::now_from`].
+    pub fn now<S: SmonthSchedule>(epoch: NaiveDate, year: &SmoitalYear<S>) -> Option<Self> {
+        Self::now_from(Utc::now(), epoch, year)
+    }
+
+    /// The instant at `utc`, where `epoch` is the Gregorian date on which day 0
+    /// of `year` begins. Each day spans one sol; the fraction of the sol elapsed
+    /// is scaled onto that day's civil length to give `sol_seconds`.
+    ///
+    /// Instants past the end of the year roll into the following years, which
+    /// are assumed to share its schedule. Returns `None` for instants before the
+    /// epoch.
+    pub fn now_from<S: SmonthSchedule>(
+        utc: DateTime<Utc>,
+        epoch: NaiveDate,
+        year: &SmoitalYear<S>,
+    ) -> Option<Self> {
+        let day = year.day_from_utc(utc, epoch)?;
+        let start = epoch.and_hms_opt(0, 0, 0)?.and_utc();
+        let elapsed = (utc - start).num_milliseconds() as f64 / 1000.0;
+
+        let fraction = (elapsed - day as f64 * SOL_LENGTH_SECONDS) / SOL_LENGTH_SECONDS;
+        let day_in_year = day % year.total_days();
+        let day_secs = year.schedule().day_length_minutes(day_in_year) as f64 * 60.0;
+        Some(SmoitalInstant {
+            date: year.date_from_day(day),
+            sol_seconds: (fraction * day_secs).clamp(0.0, day_secs),
+        })
+    }
+
+    /// Advance (or rewind, for negative durations) by `duration`, rolling over
+    /// day boundaries using each day's civil length from the year's schedule.
+    ///
+    /// Returns `None` if the instant does not belong to `year` or the result
+    /// falls outside it.
+    pub fn add_duration<S: SmonthSchedule>(
+        &self,
+        duration: MarsDuration,
+        year: &SmoitalYear<S>,
+    ) -> Option<SmoitalInstant> {
+        let schedule = year.schedule();
+        let mut day = year.day_of_year(&self.date)?;
+        let mut secs = self.sol_seconds + duration.as_earth_seconds();
+        if !secs.is_finite() {
+            return None;
+        }
+
+        // Each pass moves one day, so leaving the year bounds the loop.
+        loop {
+            if day >= year.total_days() {
+                return None;
+            }
+            let day_secs = schedule.day_length_minutes(day) as f64 * 60.0;
+            if secs < 0.0 {
+                day = day.checked_sub(1)?;
+                secs += schedule.day_length_minutes(day) as f64 * 60.0;
+            } else if secs >= day_secs {
+                secs -= day_secs;
+                day += 1;
+            } else {
+                break;
+            }
+        }
+
+        Some(SmoitalInstant {
+            date: year.try_date_from_day(day)?,
+            sol_seconds: secs,
+        })
+    }
+
+    /// Civil time elapsed from `earlier` to `self`, the inverse of
+    /// [`SmoitalInstant::add_duration`]. Each day in between contributes its
+    /// civil length from the schedule, so a Smol Day counts as 24h 00m rather
+    /// than 24h 40m. Negative if `earlier` is actually later.
+    ///
+    /// Returns `None` if either instant does not belong to `year`.
+    pub fn duration_since<S: SmonthSchedule>(
+        &self,
+        earlier: &SmoitalInstant,
+        year: &SmoitalYear<S>,
+    ) -> Option<MarsDuration> {
+        let schedule = year.schedule();
+        let from = year.day_of_year(&earlier.date)?;
+        let to = year.day_of_year(&self.date)?;
+
+        let days_secs: f64 = (from.min(to)..from.max(to))
+            .map(|day| schedule.day_length_minutes(day) as f64 * 60.0)
+            .sum();
+        let days_secs = if to < from { -days_secs } else { days_secs };
+
+        Some(MarsDuration::from_earth_seconds(
+            days_secs + self.sol_seconds - earlier.sol_seconds,
+        ))
+    }
+
+    /// Parses the timestamp form written by [`InstantInYear`]'s `Display`,
+    /// e.g. `2090-S06-37 24:00:00 UTC-12:00`.
+    ///
+    /// The time may equal the day's full civil length, so a Smol Day can end
+    /// at `24:00:00`. Returns `None` if the string is malformed, the date is not
+    /// in `year`, or the offset differs from the schedule's for that date.
+    pub fn parse<S: SmonthSchedule>(s: &str, year: &SmoitalYear<S>) -> Option<SmoitalInstant> {
+        let mut parts = s.split(' ');
+        let (Some(date), Some(time), Some(offset), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return None;
+        };
+
+        let date: SmoitalDate = date.parse().ok()?;
+        let day = year.day_of_year(&date)?;
+        if offset.strip_prefix("UTC")? != format_offset(year.timezone_offset_for_day(day)) {
+            return None;
+        }
+
+        let mut fields = time.split(':');
+        let (Some(h), Some(m), Some(sec), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return None;
+        };
+        let [h, m, sec] = [h, m, sec].map(|field| {
+            (field.len() == 2 && field.bytes().all(|b| b.is_ascii_digit()))
+                .then(|| field.parse::<u32>().ok())
+                .flatten()
+        });
+        let (h, m, sec) = (h?, m?, sec?);
+        if m >= 60 || sec >= 60 {
+            return None;
+        }
+
+        let sol_seconds = f64::from(h * 3600 + m * 60 + sec);
+        if sol_seconds > year.schedule().day_length_minutes(day) as f64 * 60.0 {
+            return None;
+        }
+
+        Some(SmoitalInstant { date, sol_seconds })
+    }
+
+    /// Binds this instant to `year`, enabling schedule-aware operators.
+    ///
+    /// Returns `None` if the instant's date does not belong to `year`.
+    pub fn in_year<'a, S: SmonthSchedule>(
+        &'a self,
+        year: &'a SmoitalYear<S>,
+    ) -> Option<InstantInYear<'a, S>> {
+        Some(InstantInYear {
+            instant: self,
+            year,
+            offset: year.timezone_offset_for_date(&self.date)?,
+        })
+    }
+}
+
+/// A [`SmoitalInstant`] together with the year whose schedule gives its days
+/// their lengths. Subtracting two of them yields the civil time between them,
+/// as [`SmoitalInstant::duration_since`].
+///
+/// Its `Display` renders a full timestamp, `2090-S06-37 23:30:00 UTC-12:00`,
+/// with whole civil seconds and the date's offset. Built with
+/// [`SmoitalInstant::in_year`], which checks that the date is in the year.
+#[derive(Debug)]
+pub struct InstantInYear<'a, S: SmonthSchedule> {
+    instant: &'a SmoitalInstant,
+    year: &'a SmoitalYear<S>,
+    offset: FixedOffset,
+}
+
+impl<'a, S: SmonthSchedule> InstantInYear<'a, S> {
+    /// The bound instant.
+    pub fn instant(&self) -> &'a SmoitalInstant {
+        self.instant
+    }
+
+    /// The year the instant belongs to.
+    pub fn year(&self) -> &'a SmoitalYear<S> {
+        self.year
+    }
+}
+
+impl<S: SmonthSchedule> Clone for InstantInYear<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: SmonthSchedule> Copy for InstantInYear<'_, S> {}
+
+impl<S: SmonthSchedule> Sub for InstantInYear<'_, S> {
+    type Output = Option<MarsDuration>;
+
+    fn sub(self, earlier: Self) -> Option<MarsDuration> {
+        self.instant.duration_since(earlier.instant, self.year)
+    }
+}
+
+impl<S: SmonthSchedule> fmt::Display for InstantInYear<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.instant.sol_seconds.floor() as u32;
+
+        write!(
+            f,
+            "{} {:02}:{:02}:{:02} UTC{}",
+            self.instant.date,
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60,
+            format_offset(self.offset)
+        )
+    }
+}
@@ -14,12 +14,16 @@ pub mod constants;
 pub mod date;
 pub mod duration;
 pub mod iana;
+pub mod instant;
 pub mod schedule;
 pub mod year;
 
 // Re-exports for easier access
-pub use clock::{DisplayMode, SmoitalClock};
-pub use date::SmoitalDate;
-pub use duration::MarsDuration;
-pub use schedule::{HeuristicSchedule, SmonthSchedule};
+pub use clock::{DisplayMode, SmoitalClock, SolClockCache};
+pub use date::{SmoitalDate, SmoitalDateError, SmolLabel};
+pub use duration::{DurationError, FiniteMarsDuration, MarsDuration};
+pub use instant::{InstantInYear, SmoitalInstant};
+pub use schedule::{
+    HeuristicSchedule, ScheduleError, SlideDirection, SmoitalConfig, SmonthSchedule,
+};
 pub use year::SmoitalYear;
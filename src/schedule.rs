@@ -1,4 +1,6 @@
 use chrono::FixedOffset;
+use std::fmt;
+use std::ops::Range;
 
 use crate::constants::*;
 
@@ -16,21 +18,355 @@ pub trait SmonthSchedule {
         }
     }
 
-    /// Calculates the UTC offset for a specific day of the year (0-667).
+    /// Returns the number of Smonths in a year.
+    fn num_smonths(&self) -> u32 {
+        SMONTHS_PER_YEAR
+    }
+
+    /// Returns the number of days in a year (the sum of all Smonth lengths).
+    ///
+    /// This is the schedule's year length: override it (or `num_smonths`) for
+    /// layouts with a different Smonth count, and every conversion follows. The
+    /// Equatorial year is 18 * 36 + 7 = 655 days, ~13.6 sols short of the
+    /// 668.6-sol Mars year ([`MARS_YEAR_SOLS`]), so 668 is not a calendar length.
+    fn total_days(&self) -> u32 {
+        (0..self.num_smonths())
+            .map(|idx| self.get_smonth_length(idx))
+            .sum()
+    }
+
+    /// Number of 37-day Smonths in the year.
+    fn smol_month_count(&self) -> u32 {
+        (0..self.num_smonths())
+            .filter(|&idx| self.is_smol_smonth(idx))
+            .count() as u32
+    }
+
+    /// Converts a Smonth (0-indexed) and Day-of-Smonth (1-indexed) to a day-of-year.
+    /// Returns `None` if the Smonth is outside the year or the day exceeds its length.
+    fn day_of_year(&self, smonth: u32, day: u32) -> Option<u32> {
+        if smonth >= self.num_smonths() || day == 0 || day > self.get_smonth_length(smonth) {
+            return None;
+        }
+
+        let preceding: u32 = (0..smonth).map(|idx| self.get_smonth_length(idx)).sum();
+        Some(preceding + (day - 1))
+    }
+
+    /// Locates the Smonth containing a day-of-year, returning
+    /// `(smonth_index, day_of_smonth)` with a 1-based day, or `None` past the year end.
+    fn smonth_of_day(&self, day_of_year: u32) -> Option<(u32, u32)> {
+        let mut remaining = day_of_year;
+        for smonth_idx in 0..self.num_smonths() {
+            let smonth_len = self.get_smonth_length(smonth_idx);
+            if remaining < smonth_len {
+                return Some((smonth_idx, remaining + 1));
+            }
+            remaining -= smonth_len;
+        }
+        None
+    }
+
+    /// ISO-8601 `±HH:MM` designators for the first `days` days of the year.
+    fn iso_designators(&self, days: u32) -> Vec<String> {
+        (0..days)
+            .map(|day| format_offset(self.get_timezone_offset(day)))
+            .collect()
+    }
+
+    /// Offsets of the first and last day of the year. Comparing the last of one
+    /// year with the first of the next shows the jump at the year boundary.
+    fn boundary_offsets(&self) -> (FixedOffset, FixedOffset) {
+        let last = self.total_days().saturating_sub(1);
+        (self.get_timezone_offset(0), self.get_timezone_offset(last))
+    }
+
+    /// The distinct offsets used over the first `days` days, sorted from
+    /// westernmost to easternmost.
+    fn distinct_offsets(&self, days: u32) -> Vec<FixedOffset> {
+        let mut minutes: Vec<i32> = (0..days).map(|day| self.offset_minutes(day)).collect();
+        minutes.sort_unstable();
+        minutes.dedup();
+        minutes
+            .into_iter()
+            .map(|m| FixedOffset::east_opt(m * 60).unwrap())
+            .collect()
+    }
+
+    /// A POSIX `TZ` string (`STDoffset`) pinning the offset of a single day,
+    /// for systems that only support static zones. This is a one-day snapshot:
+    /// the real offset changes daily.
+    ///
+    /// POSIX offsets count hours *west* of UTC, so UTC+12:00 is `MARS-12:00`.
+    fn posix_tz_approx(&self, day_of_year: u32) -> String {
+        let minutes = self.offset_minutes(day_of_year);
+        let sign = if minutes > 0 { "-" } else { "" };
+        format!(
+            "MARS{}{:02}:{:02}",
+            sign,
+            minutes.abs() / 60,
+            minutes.abs() % 60
+        )
+    }
+
+    /// Which way the offset slides from one regular day to the next, judged by
+    /// the first pair of consecutive non-Smol days whose offsets differ. A
+    /// schedule whose offset never changes reports the usual `Westward`.
+    fn slide_direction(&self) -> SlideDirection {
+        (1..self.total_days())
+            .filter(|&day| !self.is_smol_day(day - 1) && !self.is_smol_day(day))
+            .map(|day| wrap_offset_minutes(self.offset_minutes(day) - self.offset_minutes(day - 1)))
+            .find(|&delta| delta != 0)
+            .map_or(SlideDirection::Westward, |delta| {
+                if delta < 0 {
+                    SlideDirection::Westward
+                } else {
+                    SlideDirection::Eastward
+                }
+            })
+    }
+
+    /// Returns true if the day is a Smol Day, identified by its offset being
+    /// pinned at UTC-12:00.
+    fn is_smol_day(&self, day_of_year: u32) -> bool {
+        self.offset_minutes(day_of_year) == -12 * 60
+    }
+
+    /// Day indices (0-indexed, ascending) of the year's Smol Days. The default
+    /// scans the year with [`SmonthSchedule::is_smol_day`].
+    fn smol_days(&self) -> Vec<u32> {
+        (0..self.total_days())
+            .filter(|&day| self.is_smol_day(day))
+            .collect()
+    }
+
+    /// Minutes the offset slides back on a regular day, i.e. how much longer
+    /// than a Smol Day it is. Defaults to `SLIDE_MINUTES` (40).
+    fn slide_minutes(&self) -> i32 {
+        SLIDE_MINUTES
+    }
+
+    /// Civil length of a day in minutes: 24h 00m on Smol Days, 24h plus half
+    /// the slide on partial Smol Days and 24h plus the full slide otherwise
+    /// (24h 20m and 24h 40m with the default slide).
+    fn day_length_minutes(&self, day_of_year: u32) -> i64 {
+        let slide = i64::from(self.slide_minutes());
+        if self.is_smol_day(day_of_year) {
+            SMOL_DAY_MINS
+        } else if self.partial_smol_days().contains(&day_of_year) {
+            SMOL_DAY_MINS + slide / 2
+        } else {
+            SMOL_DAY_MINS + slide
+        }
+    }
+
+    /// Accumulated seconds by which the civil clock has run ahead of physical
+    /// time (positive) or behind it (negative) at the start of `day_of_year`.
+    ///
+    /// Every standard day is ~24.8s longer than a sol; each Smol Day is
+    /// ~39.6 minutes shorter and pulls the clock back.
+    fn civil_vs_physical_drift(&self, day_of_year: u32) -> f64 {
+        (0..day_of_year)
+            .map(|day| self.day_length_minutes(day) as f64 * 60.0 - SOL_LENGTH_SECONDS)
+            .sum()
+    }
+
+    /// Minutes removed from the civil clock by the Smol Days among the first
+    /// `days` days: one [`SmonthSchedule::slide_minutes`] per Smol Day.
+    fn total_smol_correction_minutes(&self, days: u32) -> i64 {
+        let smol_days = (0..days).filter(|&day| self.is_smol_day(day)).count() as i64;
+        smol_days * i64::from(self.slide_minutes())
+    }
+
+    /// How many days from now (0 = today) the next Smol Day should fall to best
+    /// cancel `accumulated_drift_seconds` of civil-vs-physical drift (see
+    /// [`SmonthSchedule::civil_vs_physical_drift`]).
+    ///
+    /// Drift grows ~24.8s per standard day and a Smol Day removes ~2375s, so the
+    /// Smol Day goes where it leaves the drift closest to zero. Returns `None`
+    /// if the drift is not finite or the answer lies beyond a year.
+    fn recommended_next_smol(&self, accumulated_drift_seconds: f64) -> Option<u32> {
+        if !accumulated_drift_seconds.is_finite() {
+            return None;
+        }
+        let standard_minutes = SMOL_DAY_MINS + i64::from(self.slide_minutes());
+        let standard_step = (standard_minutes * 60) as f64 - SOL_LENGTH_SECONDS;
+        let smol_step = (SMOL_DAY_MINS * 60) as f64 - SOL_LENGTH_SECONDS;
+
+        let days = ((-smol_step - accumulated_drift_seconds) / standard_step)
+            .round()
+            .max(0.0);
+        (days < self.total_days() as f64).then_some(days as u32)
+    }
+
+    /// The "Quiet Period": the longest contiguous run of standard (36-day)
+    /// Smonths, as a range of days. Returns `None` if every Smonth is long.
+    fn quiet_period(&self) -> Option<Range<u32>> {
+        let mut runs = Vec::new();
+        let mut run_start = 0;
+        let mut day = 0;
+
+        for smonth_idx in 0..self.num_smonths() {
+            let smonth_len = self.get_smonth_length(smonth_idx);
+            if self.is_smol_smonth(smonth_idx) {
+                runs.push(run_start..day);
+                run_start = day + smonth_len;
+            }
+            day += smonth_len;
+        }
+        runs.push(run_start..day);
+
+        runs.into_iter().filter(|run| !run.is_empty()).fold(
+            None,
+            |best: Option<Range<u32>>, run| match best {
+                Some(b) if b.len() >= run.len() => Some(b),
+                _ => Some(run),
+            },
+        )
+    }
+
+    /// Approximate perihelion (~Ls 251), around which the long Smonths cluster:
+    /// the median of the year's Smol Days. Returns `None` if there are none.
+    fn perihelion_day(&self) -> Option<u32> {
+        let smol_days = self.smol_days();
+        let mid = smol_days.len() / 2;
+        match smol_days.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(smol_days[mid]),
+            _ => Some((smol_days[mid - 1] + smol_days[mid]) / 2),
+        }
+    }
+
+    /// Number of days from `day_of_year` until the offset wraps back across the
+    /// 24-hour boundary (i.e. jumps up instead of sliding down). Returns `None`
+    /// if no wrap occurs within a year's worth of days.
+    fn days_until_wrap(&self, day_of_year: u32) -> Option<u32> {
+        let mut previous = self.offset_minutes(day_of_year);
+        for n in 1..=self.total_days() {
+            let current = self.offset_minutes(day_of_year.checked_add(n)?);
+            if current > previous {
+                return Some(n);
+            }
+            previous = current;
+        }
+        None
+    }
+
+    /// Day indices (0-indexed) on which the clock only slides by half the usual
+    /// 40 minutes, making the day 24h 20m long. Empty by default.
+    fn partial_smol_days(&self) -> Vec<u32> {
+        vec![]
+    }
+
+    /// Calculates the UTC offset for a specific day of the year (0-indexed).
     /// This requires iterating through the Smonths to find which one the day falls into.
-    fn get_timezone_offset(&self, day_of_year: u32) -> FixedOffset;
+    fn get_timezone_offset(&self, day_of_year: u32) -> FixedOffset {
+        FixedOffset::east_opt(self.offset_minutes(day_of_year) * 60).unwrap()
+    }
+
+    /// The UTC offset for a day-of-year in signed minutes.
+    ///
+    /// The default walks the Smonths: the 37th day of a 37-day Smonth is a Smol Day
+    /// pinned at UTC-12:00, and every other day follows `760 - 40 * D`. Each
+    /// partial Smol Day holds the days after it 20 minutes above the formula until
+    /// the next Smol Day resets the clock. Days past the end of the year roll into
+    /// the next one, as the schedule repeats every year.
+    fn offset_minutes(&self, day_of_year: u32) -> i32 {
+        walk_offset_minutes(self, day_of_year)
+    }
+
+    /// The offset in minutes for a regular (non-Smol) day of a Smonth, given its
+    /// 1-based day-of-Smonth. Defaults to the linear `760 - 40 * D`, wrapped into
+    /// (-12:00, +12:00]. Override to swap in a different formula while keeping
+    /// the default Smonth walk and Smol Day handling.
+    fn raw_offset_minutes(&self, day_of_smonth: u32) -> i32 {
+        linear_offset_minutes(12 * 60, day_of_smonth)
+    }
+}
+
+/// Direction in which a schedule's UTC offset moves from day to day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlideDirection {
+    /// The offset decreases, e.g. +12:00 to +11:20 (the standard slide).
+    Westward,
+    /// The offset increases.
+    Eastward,
+}
+
+/// Default offset walk: locates the day's Smonth, pins Smol Days and defers
+/// every other day to [`SmonthSchedule::raw_offset_minutes`].
+fn walk_offset_minutes<S: SmonthSchedule + ?Sized>(schedule: &S, day_of_year: u32) -> i32 {
+    // 1. Determine which Smonth we are in and the Day-of-Smonth (1-based).
+    let day_in_year = day_of_year % schedule.total_days();
+    let (smonth_idx, day_of_smonth) = schedule
+        .smonth_of_day(day_in_year)
+        .expect("day is within the year");
+
+    // 2. Apply Smoital Logic
+    // If it is the 37th day of a 37-day month, it is a Smol Day (UTC-12:00)
+    let is_long_month = schedule.get_smonth_length(smonth_idx) == 37;
+
+    if is_long_month && day_of_smonth == 37 {
+        return -12 * 60;
+    }
+
+    // Otherwise, use the day formula (by default Offset = 760 - 40 * D).
+    let offset = schedule.raw_offset_minutes(day_of_smonth);
+
+    // Each earlier partial Smol Day only slid back half as far, and that
+    // carries forward until a Smol Day resets the clock.
+    let carried = schedule
+        .partial_smol_days()
+        .into_iter()
+        .filter(|&partial| partial < day_in_year)
+        .filter(|&partial| {
+            let next_smol = (0..schedule.num_smonths())
+                .filter_map(|smonth| schedule.day_of_year(smonth, 37))
+                .find(|&smol| smol >= partial);
+            next_smol.is_none_or(|smol| smol >= day_in_year)
+        })
+        .count() as i32;
+
+    wrap_offset_minutes(offset + carried * (SLIDE_MINUTES / 2))
+}
+
+/// The linear formula `start + 40 - 40 * D`, wrapped into (-12:00, +12:00].
+/// `start_minutes` is the offset on the first day of every Smonth.
+fn linear_offset_minutes(start_minutes: i32, day_of_smonth: u32) -> i32 {
+    wrap_offset_minutes(start_minutes + SLIDE_MINUTES - SLIDE_MINUTES * day_of_smonth as i32)
+}
+
+/// Wraps an offset in minutes into the range (-720, 720].
+fn wrap_offset_minutes(minutes: i32) -> i32 {
+    // Reduce first so extreme inputs cannot overflow.
+    720 - (720 - minutes.rem_euclid(1440)).rem_euclid(1440)
+}
+
+/// Formats an offset as an ISO-8601 time zone designator (e.g. `+12:00`).
+pub fn format_offset(offset: FixedOffset) -> String {
+    let minutes = offset.local_minus_utc() / 60;
+    let sign = if minutes < 0 { '-' } else { '+' };
+    format!(
+        "{}{:02}:{:02}",
+        sign,
+        minutes.abs() / 60,
+        minutes.abs() % 60
+    )
 }
 
 /// Implements the "Equatorial Smoital Schedule".
 ///
 /// This schedule is optimized for equatorial regions and is designed to be used
 /// consistently every year. It features a "Quiet Period" (Period 1) of
-/// standard 36-day Smonths during the long-day season (Perihelion), followed
-/// by a cluster of 37-day (Smol) Smonths.
+/// standard 36-day Smonths around aphelion (~Ls 71), when apparent solar days
+/// are short, followed by a cluster of 37-day (Smol) Smonths around perihelion
+/// (~Ls 251; see [`SmonthSchedule::perihelion_day`]), when they are long.
 ///
 /// Pattern:
-/// - Period 1 (Perihelion): ~7 Smonths of 36 days.
-/// - Period 2 & 3: Alternating 37-day Smonths to correct the equation of time.
+/// - Period 1 (Aphelion): 6 Smonths of 36 days (days 0..216).
+/// - Period 2 & 3 (Perihelion): Alternating 37-day Smonths to correct the
+///   equation of time.
+#[derive(Debug, Clone, PartialEq)]
 pub struct EquatorialSchedule {
     /// The index of the first "Long" (37-day) Smonth.
     /// Based on the paper's heuristics, this is typically index 5 or 6.
@@ -38,6 +374,8 @@ pub struct EquatorialSchedule {
     /// The relative offsets of the 37-day months from the first long smonth.
     /// Corresponds to the pattern [1, 2, 4, 5, 7, 9, 11].
     long_smonth_offsets: [u32; 7],
+    /// Offset in minutes on the first day of every Smonth (+12:00 by default).
+    start_offset_minutes: i32,
 }
 
 impl Default for EquatorialSchedule {
@@ -51,14 +389,56 @@ impl Default for EquatorialSchedule {
             // Here we map them 0-based relative to `first_long_smonth_index`.
             // Pattern logic:
             // Index 6 (37), Index 7 (37), Index 8 (36), Index 9 (37), Index 10 (37)...
+            start_offset_minutes: 12 * 60,
         }
     }
 }
 
+/// Known `(day_of_year, offset_minutes)` pairs for the Equatorial schedule.
+const PAPER_OFFSETS: [(u32, i32); 9] = [
+    (0, 720),   // Start of year: +12:00
+    (1, 680),   // 40 minutes earlier each day
+    (18, 0),    // Day 19 of the first Smonth: UTC
+    (35, -680), // Last day of a 36-day Smonth
+    (36, 720),  // Next Smonth restarts at +12:00
+    (216, 720), // Start of the first long Smonth
+    (251, -680),
+    (252, -720), // Smol Day pinned at UTC-12:00
+    (253, 720),
+];
+
 impl EquatorialSchedule {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Equatorial layout whose Smonths start the slide from `minutes` instead
+    /// of +12:00, wrapped into (-12:00, +12:00]. Smol Days stay pinned at
+    /// UTC-12:00.
+    pub fn with_start_offset(minutes: i32) -> Self {
+        Self {
+            start_offset_minutes: wrap_offset_minutes(minutes),
+            ..Self::default()
+        }
+    }
+
+    /// Checks this schedule against the offsets published in the paper.
+    /// On failure, returns the `(day, expected_minutes, actual_minutes)` mismatches.
+    pub fn verify_against_paper(&self) -> Result<(), Vec<(u32, i32, i32)>> {
+        let mismatches: Vec<(u32, i32, i32)> = PAPER_OFFSETS
+            .iter()
+            .filter_map(|&(day, expected)| {
+                let actual = self.offset_minutes(day);
+                (actual != expected).then_some((day, expected, actual))
+            })
+            .collect();
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatches)
+        }
+    }
 }
 
 impl SmonthSchedule for EquatorialSchedule {
@@ -74,32 +454,159 @@ impl SmonthSchedule for EquatorialSchedule {
         self.long_smonth_offsets.contains(&relative_index)
     }
 
-    fn get_timezone_offset(&self, day_of_year: u32) -> FixedOffset {
-        let mut current_day_sum = 0;
-        let mut smonth_idx = 0;
+    fn raw_offset_minutes(&self, day_of_smonth: u32) -> i32 {
+        linear_offset_minutes(self.start_offset_minutes, day_of_smonth)
+    }
+}
+
+/// Implements a polar-optimized Smoital Schedule.
+///
+/// Away from the equator the seasonal swing of the equation of time dominates,
+/// so the long (Smol) Smonths are clustered around aphelion (~Ls 71, early in
+/// the year) instead of perihelion. The cluster straddles the year boundary,
+/// and its spacing mirrors the Equatorial one: sparse on the way in, dense on
+/// the way out. Offsets within each Smonth follow the same `760 - 40 * D` slide.
+///
+/// The year has the same seven 37-day Smonths, and so the same length, as
+/// [`EquatorialSchedule`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolarSchedule {
+    /// The index of the first "Long" (37-day) Smonth. The cluster wraps past
+    /// the end of the year into the following Smonths.
+    first_long_smonth_index: u32,
+    /// The relative offsets of the 37-day months from the first long smonth,
+    /// taken modulo the number of Smonths.
+    long_smonth_offsets: [u32; 7],
+}
 
-        // 1. Determine which Smonth we are in
-        while current_day_sum + self.get_smonth_length(smonth_idx) <= day_of_year {
-            current_day_sum += self.get_smonth_length(smonth_idx);
-            smonth_idx += 1;
+impl Default for PolarSchedule {
+    fn default() -> Self {
+        Self {
+            // Centres the cluster on Smonth 2 (days ~72-107), around aphelion.
+            first_long_smonth_index: 15,
+            // The Equatorial spacing [0, 1, 3, 4, 6, 8, 10], reversed.
+            // Long Smonths: 15, 17, 1, 3, 4, 6, 7.
+            long_smonth_offsets: [0, 2, 4, 6, 7, 9, 10],
         }
+    }
+}
+
+impl PolarSchedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SmonthSchedule for PolarSchedule {
+    fn is_smol_smonth(&self, smonth_index: u32) -> bool {
+        if smonth_index >= SMONTHS_PER_YEAR {
+            return false;
+        }
+
+        let relative_index =
+            (smonth_index + SMONTHS_PER_YEAR - self.first_long_smonth_index) % SMONTHS_PER_YEAR;
+        self.long_smonth_offsets.contains(&relative_index)
+    }
+
+    /// Perihelion is a property of the orbit, not of the layout, so this
+    /// reports the same day as the Equatorial schedule rather than the middle
+    /// of this schedule's (aphelion) cluster.
+    fn perihelion_day(&self) -> Option<u32> {
+        EquatorialSchedule::new().perihelion_day()
+    }
+}
+
+/// A schedule with an arbitrary set of 37-day Smonths, for trying out
+/// alternate layouts without writing a new [`SmonthSchedule`] impl.
+///
+/// Offsets follow the default walk: Smol Days are pinned at UTC-12:00 and
+/// every other day slides by `760 - 40 * D`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomSchedule {
+    /// Sorted, deduplicated indices of the long (37-day) Smonths.
+    long_indices: Vec<u32>,
+}
+
+impl CustomSchedule {
+    /// Marks exactly the Smonths at `indices` (0-based, in any order,
+    /// duplicates allowed) as 37 days long.
+    ///
+    /// Returns `ScheduleError::SmonthOutOfRange` for an index past the last
+    /// Smonth, and `ScheduleError::NoSmolDays` if `indices` is empty.
+    pub fn from_long_indices(indices: &[u32]) -> Result<Self, ScheduleError> {
+        if let Some(&idx) = indices.iter().find(|&&idx| idx >= SMONTHS_PER_YEAR) {
+            return Err(ScheduleError::SmonthOutOfRange(idx));
+        }
+        if indices.is_empty() {
+            return Err(ScheduleError::NoSmolDays);
+        }
+
+        let mut long_indices = indices.to_vec();
+        long_indices.sort_unstable();
+        long_indices.dedup();
+        Ok(Self { long_indices })
+    }
 
-        // 2. Determine Day-of-Smonth (1-based)
-        let day_of_smonth = day_of_year - current_day_sum + 1;
+    /// The long Smonth indices, sorted and without duplicates.
+    pub fn long_indices(&self) -> &[u32] {
+        &self.long_indices
+    }
+}
+
+impl SmonthSchedule for CustomSchedule {
+    fn is_smol_smonth(&self, smonth_index: u32) -> bool {
+        self.long_indices.binary_search(&smonth_index).is_ok()
+    }
+}
 
-        // 3. Apply Smoital Logic
-        // If it is the 37th day of a 37-day month, it is a Smol Day (UTC-12:00)
-        let is_long_month = self.get_smonth_length(smonth_idx) == 37;
+/// Errors produced when building a schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleError {
+    /// The parameters place every Smol Day outside the year, which would leave
+    /// the clock drifting without ever resetting to UTC-12:00.
+    NoSmolDays,
+    /// A Smonth index past the end of the year (valid indices are 0..=17).
+    SmonthOutOfRange(u32),
+    /// A slide (rounding grid) of zero or fewer minutes.
+    InvalidSlide(i32),
+}
 
-        if is_long_month && day_of_smonth == 37 {
-            return FixedOffset::west_opt(12 * 3600).unwrap();
+impl fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleError::NoSmolDays => write!(f, "schedule has no smol days within the year"),
+            ScheduleError::SmonthOutOfRange(idx) => write!(f, "smonth index {idx} out of range"),
+            ScheduleError::InvalidSlide(minutes) => {
+                write!(f, "slide of {minutes} minutes is not positive")
+            }
         }
+    }
+}
 
-        // Otherwise, use the standard formula: Offset = 760 - 40 * D
-        // This calculates the offset in minutes.
-        let offset_minutes = 760 - (40 * day_of_smonth as i32);
+impl std::error::Error for ScheduleError {}
 
-        FixedOffset::east_opt(offset_minutes * 60).unwrap()
+/// Tunable parameters for the Heuristic Algorithm.
+///
+/// The defaults reproduce the constants published in the paper.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SmoitalConfig {
+    pub c1_seconds: f64,
+    pub c2: f64,
+    pub c3: f64,
+    pub c4: f64,
+    /// Size of the daily offset slide and of the rounding grid, in minutes.
+    pub slide_minutes: i32,
+}
+
+impl Default for SmoitalConfig {
+    fn default() -> Self {
+        Self {
+            c1_seconds: C1_SECONDS,
+            c2: C2,
+            c3: C3,
+            c4: C4,
+            slide_minutes: SLIDE_MINUTES,
+        }
     }
 }
 
@@ -107,99 +614,154 @@ impl SmonthSchedule for EquatorialSchedule {
 ///
 /// This calculates the precise timezone schedule for any year based on a
 /// reference "Natural Timezone" (Mean Solar Time offset).
+#[derive(Debug, Clone, PartialEq)]
 pub struct HeuristicSchedule {
     natural_tz_start: f64,
-    smol_dates: Vec<u32>, // Day indices (0-indexed) that are Smol
+    smol_dates: Vec<u32>,   // Day indices (0-indexed) that are Smol
+    long_smonths: Vec<u32>, // The 37-day Smonths whose last day is a Smol Day
+    config: SmoitalConfig,
 }
 
 impl HeuristicSchedule {
     pub fn new(_year: i32, natural_tz_min: f64) -> Self {
+        // The published constants always place Smol Days within the year.
+        Self::build(natural_tz_min, SmoitalConfig::default())
+    }
+
+    /// Same as [`HeuristicSchedule::new`] but with custom heuristic parameters.
+    ///
+    /// Returns `ScheduleError::InvalidSlide` if `config.slide_minutes` is not
+    /// positive, and `ScheduleError::NoSmolDays` if the parameters push every
+    /// Smol Day past the end of the year.
+    pub fn with_config(
+        _year: i32,
+        natural_tz_min: f64,
+        config: SmoitalConfig,
+    ) -> Result<Self, ScheduleError> {
+        if config.slide_minutes <= 0 {
+            return Err(ScheduleError::InvalidSlide(config.slide_minutes));
+        }
+        let schedule = Self::build(natural_tz_min, config);
+        if schedule.smol_dates.is_empty() {
+            return Err(ScheduleError::NoSmolDays);
+        }
+        Ok(schedule)
+    }
+
+    fn build(natural_tz_min: f64, config: SmoitalConfig) -> Self {
+        let grid = config.slide_minutes as f64;
+
         // SmoitalTZ_{y,0} calculation
-        let raw_start = natural_tz_min + (C1_SECONDS / 60.0);
-        let _start_offset = Self::wrap_24hr(Self::round_40min(raw_start));
+        let raw_start = natural_tz_min + (config.c1_seconds / 60.0);
+        let _start_offset = Self::wrap_24hr(Self::round_to_grid(raw_start, grid));
 
         // Smoitus Factor
-        let smoitus_factor = ((raw_start / 40.0) + 0.5).fract();
+        let smoitus_factor = ((raw_start / grid) + 0.5).fract();
 
         // SmonthStart approximation
         let smonth_start_y0 = 0.0;
 
         // FirstLongSmonth
-        let c2 = C2;
-        let c3 = C3;
-        let c4 = C4;
+        let c2 = config.c2;
+        let c3 = config.c3;
+        let c4 = config.c4;
         let fls_val = c2 + (smoitus_factor * c3) + (smonth_start_y0 / c4);
         let first_long_smonth = fls_val.floor() as i32;
 
         let smol_days_in_year = 6;
 
-        // Smol Date Generation using the heuristic spacing pattern
+        // Long Smonth generation using the heuristic spacing pattern
         // Pattern of spacing relative to first_long_smonth: [1, 2, 4, 5, 7, 9, 11]
         // Note: The previous struct used 0-based local offsets, this uses the paper's
-        // 1-based indexing logic for the calculation loop.
+        // 1-based indexing logic, so the n-th long Smonth is FirstLongSmonth + Spacing - 1.
         let spacings = [1, 2, 4, 5, 7, 9, 11];
-        let mut smol_dates = Vec::new();
-
-        for n in 0..smol_days_in_year {
-            let spacing_idx = n as usize;
-            if spacing_idx < spacings.len() {
-                let s_month_offset = spacings[spacing_idx];
-                // Formula approx: 36 * (FirstLongSmonth + Spacing) + n
-                // We clamp to ensure valid day indices.
-                let date_idx = 36 * (first_long_smonth + s_month_offset) + n;
-                if date_idx >= 0 {
-                    smol_dates.push(date_idx as u32);
-                }
-            }
-        }
-
-        HeuristicSchedule {
+        let mut schedule = HeuristicSchedule {
             natural_tz_start: natural_tz_min,
-            smol_dates,
-        }
+            smol_dates: Vec::new(),
+            long_smonths: Vec::new(),
+            config,
+        };
+        let num_smonths = schedule.num_smonths();
+        schedule.long_smonths = spacings
+            .iter()
+            .take(smol_days_in_year)
+            .filter_map(|&spacing| {
+                let smonth = first_long_smonth.checked_add(spacing - 1)?;
+                u32::try_from(smonth).ok().filter(|&s| s < num_smonths)
+            })
+            .collect();
+
+        // Each Smol Day is the 37th day of its long Smonth, i.e. on day
+        // 36 * (FirstLongSmonth + Spacing) + n for the n-th one.
+        let smol_dates = schedule
+            .long_smonths
+            .iter()
+            .filter_map(|&smonth| schedule.day_of_year(smonth, 37))
+            .collect();
+        schedule.smol_dates = smol_dates;
+        schedule
     }
 
-    fn round_40min(tz: f64) -> f64 {
-        (tz / 40.0).round() * 40.0
+    fn round_to_grid(tz: f64, grid: f64) -> f64 {
+        (tz / grid).round() * grid
     }
 
     fn wrap_24hr(tz: f64) -> f64 {
-        let mut t = tz;
-        while t <= -720.0 {
-            t += 1440.0;
-        }
-        while t > 720.0 {
-            t -= 1440.0;
-        }
-        t
+        // Wrap into (-720, 720] without looping, so huge inputs stay cheap.
+        720.0 - (720.0 - tz).rem_euclid(1440.0)
     }
 }
 
 impl SmonthSchedule for HeuristicSchedule {
-    fn is_smol_smonth(&self, _smonth_index: u32) -> bool {
-        // The heuristic struct calculates Smol Dates directly rather than Smonth lengths,
-        // so this trait method is less applicable, but we can infer it.
-        // For simplicity in this library context, we rely on get_timezone_offset.
-        false
+    fn is_smol_smonth(&self, smonth_index: u32) -> bool {
+        // Each Smol Date is the 37th day of the Smonth recorded alongside it,
+        // so the year's length and date layout agree with the offsets.
+        self.long_smonths.contains(&smonth_index)
     }
 
-    fn get_timezone_offset(&self, day_of_year: u32) -> FixedOffset {
+    fn quiet_period(&self) -> Option<Range<u32>> {
+        // Longest gap between consecutive Smol Days (or the year boundaries).
+        let mut boundaries = vec![0];
+        boundaries.extend(self.smol_dates.iter().flat_map(|&d| [d, d + 1]));
+        boundaries.push(self.total_days());
+
+        boundaries
+            .chunks(2)
+            .map(|pair| pair[0]..pair[1])
+            .filter(|range| !range.is_empty())
+            .fold(None, |best: Option<Range<u32>>, range| match best {
+                Some(b) if b.len() >= range.len() => Some(b),
+                _ => Some(range),
+            })
+    }
+
+    fn smol_days(&self) -> Vec<u32> {
+        self.smol_dates.clone()
+    }
+
+    fn slide_minutes(&self) -> i32 {
+        self.config.slide_minutes
+    }
+
+    fn offset_minutes(&self, day_of_year: u32) -> i32 {
         // Check if Smol Day (UTC-12:00)
         if self.smol_dates.contains(&day_of_year) {
-            return FixedOffset::west_opt(12 * 3600).unwrap();
+            return -12 * 60;
         }
 
         // Calculate count of Smol days up to this day
-        let smol_count = self.smol_dates.iter().filter(|&&d| d < day_of_year).count() as i32;
+        let smol_count = self.smol_dates.iter().filter(|&&d| d < day_of_year).count() as i64;
 
-        // Formula: SmoitalTZ_{y,0} - 40 * (d - smol_count)
-        let start_offset = Self::wrap_24hr(Self::round_40min(
-            self.natural_tz_start + (C1_SECONDS / 60.0),
+        // Formula: SmoitalTZ_{y,0} - slide * (d - smol_count)
+        let grid = self.config.slide_minutes as f64;
+        let start_offset = Self::wrap_24hr(Self::round_to_grid(
+            self.natural_tz_start + (self.config.c1_seconds / 60.0),
+            grid,
         ));
-        let adjustment = 40.0 * (day_of_year as i32 - smol_count) as f64;
+        let adjustment = grid * (i64::from(day_of_year) - smol_count) as f64;
 
         let offset = Self::wrap_24hr(start_offset - adjustment);
 
-        FixedOffset::east_opt((offset * 60.0) as i32).unwrap()
+        offset as i32
     }
 }
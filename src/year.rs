@@ -1,12 +1,18 @@
-use chrono::FixedOffset;
+use chrono::{DateTime, Datelike, Days, FixedOffset, NaiveDate, Utc, Weekday};
 
-use crate::{date::SmoitalDate, schedule::SmonthSchedule};
+use crate::{
+    constants::{SMOITAL_EPOCH, SMOITAL_EPOCH_YEAR, SOL_LENGTH_SECONDS},
+    date::SmoitalDate,
+    duration::MarsDuration,
+    schedule::SmonthSchedule,
+};
 
 /// Convenience wrapper that lets you work with a specific Martian year.
 ///
 /// It ties a `SmonthSchedule` to a calendar year so you can move between
 /// day-of-year indices and `SmoitalDate` values, and ask for the appropriate
 /// timezone offset.
+#[derive(Debug, Clone, PartialEq)]
 pub struct SmoitalYear<S: SmonthSchedule> {
     pub year: i32,
     schedule: S,
@@ -23,6 +29,224 @@ impl<S: SmonthSchedule> SmoitalYear<S> {
         self.schedule.get_timezone_offset(day_of_year)
     }
 
+    /// Days of the year whose offset equals `offset` (the inverse of
+    /// [`SmoitalYear::timezone_offset_for_day`]). The slide cycles, so an
+    /// offset typically recurs once per Smonth.
+    pub fn days_with_offset(&self, offset: FixedOffset) -> Vec<u32> {
+        (0..self.total_days())
+            .filter(|&day| self.timezone_offset_for_day(day) == offset)
+            .collect()
+    }
+
+    /// Offset at a point within a day. The offset is constant across the day, but
+    /// `sol_seconds` must fall within the day's civil length (shorter on Smol Days).
+    /// Returns `None` for out-of-range days or sub-day values.
+    pub fn offset_at(&self, day_of_year: u32, sol_seconds: f64) -> Option<FixedOffset> {
+        if day_of_year >= self.total_days() {
+            return None;
+        }
+
+        let day_secs = self.schedule.day_length_minutes(day_of_year) as f64 * 60.0;
+        (0.0..day_secs)
+            .contains(&sol_seconds)
+            .then(|| self.timezone_offset_for_day(day_of_year))
+    }
+
+    /// Number of days in this year according to the attached schedule.
+    pub fn total_days(&self) -> u32 {
+        self.schedule.total_days()
+    }
+
+    /// Physical length of this year in Earth seconds.
+    ///
+    /// Every calendar day is counted as one physical sol (`SOL_LENGTH_SECONDS`).
+    /// Smol days only shorten the *civil* clock; the underlying sol is unchanged,
+    /// so they do not reduce this value. An Equatorial year of 655 sols is
+    /// 13.6 sols shorter than the orbital year
+    /// ([`MARS_YEAR_SOLS`](crate::constants::MARS_YEAR_SOLS)).
+    pub fn total_earth_seconds(&self) -> f64 {
+        self.total_days() as f64 * SOL_LENGTH_SECONDS
+    }
+
+    /// Days (after day 0) whose offset differs from the previous day, paired with
+    /// the new offset. Lets a renderer update only when the clock changes.
+    pub fn iter_transitions(&self) -> impl Iterator<Item = (u32, FixedOffset)> + '_ {
+        (1..self.total_days()).filter_map(move |day| {
+            let offset = self.timezone_offset_for_day(day);
+            (offset != self.timezone_offset_for_day(day - 1)).then_some((day, offset))
+        })
+    }
+
+    /// Collects [`SmoitalYear::iter_transitions`] into a `Vec`.
+    pub fn offset_transitions(&self) -> Vec<(u32, FixedOffset)> {
+        self.iter_transitions().collect()
+    }
+
+    /// Number of offset changes within the year.
+    pub fn offset_transition_count(&self) -> usize {
+        self.iter_transitions().count()
+    }
+
+    /// Day-of-year containing the UTC instant, where `epoch` is the Gregorian date
+    /// on which day 0 of this year begins (at 00:00 UTC) and each day spans one sol.
+    /// Instants past the end of the year keep counting into the following years,
+    /// so the result may exceed [`SmoitalYear::total_days`]; pass it to
+    /// [`SmoitalYear::date_from_day`] to roll it over. Returns `None` for instants
+    /// before the epoch.
+    pub fn day_from_utc(&self, utc: DateTime<Utc>, epoch: NaiveDate) -> Option<u32> {
+        let start = epoch.and_hms_opt(0, 0, 0)?.and_utc();
+        let elapsed = (utc - start).num_milliseconds() as f64 / 1000.0;
+        if elapsed < 0.0 {
+            return None;
+        }
+
+        let day = (elapsed / SOL_LENGTH_SECONDS).floor();
+        (day <= u32::MAX as f64).then_some(day as u32)
+    }
+
+    /// Gregorian date on which day 0 of this year begins. Years follow each
+    /// other without gaps, [`SmoitalYear::total_days`] sols apart from
+    /// [`SMOITAL_EPOCH`] (the same model as [`SmoitalYear::add_days`]), so the
+    /// start usually falls partway through this date.
+    ///
+    /// A 655-sol year is ~13.6 sols shorter than a Mars year
+    /// ([`MARS_YEAR_SOLS`](crate::constants::MARS_YEAR_SOLS)), so these starts
+    /// drift ahead of the Clancy et al. year starts by that much each year.
+    pub fn year_start_gregorian(&self) -> Option<NaiveDate> {
+        Self::gregorian_for_sol(self.epoch_sols()?)
+    }
+
+    /// First and last Gregorian dates that overlap this year, where `epoch` is
+    /// the date on which day 0 begins (see [`SmoitalYear::year_start_gregorian`]).
+    /// The year lasts [`SmoitalYear::total_earth_seconds`], so its final sol
+    /// usually ends partway through the last date.
+    pub fn gregorian_span(&self, epoch: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        let earth_days = (self.total_earth_seconds() / 86_400.0).ceil() as u64;
+        let last = epoch.checked_add_days(Days::new(earth_days.checked_sub(1)?))?;
+        Some((epoch, last))
+    }
+
+    /// Like [`SmoitalYear::to_gregorian`], but anchored on [`SMOITAL_EPOCH`]:
+    /// sols are counted across whole years from the epoch, so consecutive years
+    /// line up with no gap or overlap between them.
+    pub fn to_gregorian_anchored(&self, date: &SmoitalDate) -> Option<NaiveDate> {
+        let day = self.day_of_year(date)?;
+        Self::gregorian_for_sol(self.epoch_sols()?.checked_add(i64::from(day))?)
+    }
+
+    /// Inverse of [`SmoitalYear::to_gregorian_anchored`]: the Smoital date
+    /// beginning on (or, for a Skipped Date, spanning) the Gregorian `date`.
+    /// Dates outside this year roll into neighbouring years, which are assumed
+    /// to share this schedule. Returns `None` if the year overflows.
+    pub fn from_gregorian_anchored(&self, date: NaiveDate) -> Option<SmoitalDate> {
+        let earth_days = (date - SMOITAL_EPOCH).num_days();
+        let ratio = SOL_LENGTH_SECONDS / 86_400.0;
+        let sol = ((earth_days + 1) as f64 / ratio).ceil() as i64 - 1;
+
+        let first = self.try_date_from_day(0)?;
+        self.add_days(&first, sol.checked_sub(self.epoch_sols()?)?)
+    }
+
+    /// Gregorian (Earth) date on which `date` begins, where `epoch` is the date on
+    /// which day 0 of this year begins.
+    ///
+    /// Each Smoital day spans one physical sol, slightly longer than an Earth day,
+    /// so Earth dates advance one per day but skip a Gregorian date roughly every
+    /// 36 days (the "Skipped Date" logic, Earth-Date = S + D).
+    pub fn to_gregorian(&self, date: &SmoitalDate, epoch: NaiveDate) -> Option<NaiveDate> {
+        let day = self.day_of_year(date)?;
+        Self::gregorian_for_day(day, epoch)
+    }
+
+    /// Inverse of [`SmoitalYear::to_gregorian`]: the Smoital date beginning on
+    /// the Gregorian `date`, with `epoch` the date on which day 0 of this year
+    /// begins.
+    ///
+    /// No Smoital day begins on a Skipped Date; those return the day that spans
+    /// it. Dates past the end of this year roll into the following years, as in
+    /// [`SmoitalYear::date_from_day`]. Returns `None` for dates before `epoch`.
+    pub fn from_gregorian(&self, date: NaiveDate, epoch: NaiveDate) -> Option<SmoitalDate> {
+        let earth_days = (date - epoch).num_days();
+        if earth_days < 0 {
+            return None;
+        }
+
+        // The last day to begin before the end of this Earth date.
+        let ratio = SOL_LENGTH_SECONDS / 86_400.0;
+        let day = ((earth_days + 1) as f64 / ratio).ceil() as i64 - 1;
+        Some(self.date_from_day(u32::try_from(day).ok()?))
+    }
+
+    /// Maps every date in the year to the Gregorian date it begins on.
+    /// See [`SmoitalYear::to_gregorian`] for the mapping.
+    pub fn gregorian_crosswalk(&self, epoch: NaiveDate) -> Vec<(SmoitalDate, NaiveDate)> {
+        (0..self.total_days())
+            .filter_map(|day| {
+                Some((
+                    self.try_date_from_day(day)?,
+                    Self::gregorian_for_day(day, epoch)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Earth weekday on which a day-of-year begins (see [`SmoitalYear::to_gregorian`]).
+    /// Returns `None` for days outside the year.
+    pub fn gregorian_weekday(&self, day_of_year: u32, epoch: NaiveDate) -> Option<Weekday> {
+        let date = self.try_date_from_day(day_of_year)?;
+        self.to_gregorian(&date, epoch).map(|d| d.weekday())
+    }
+
+    fn gregorian_for_day(day_of_year: u32, epoch: NaiveDate) -> Option<NaiveDate> {
+        let earth_days = (day_of_year as f64 * SOL_LENGTH_SECONDS / 86_400.0).floor();
+        epoch.checked_add_days(Days::new(earth_days as u64))
+    }
+
+    /// Sols from the start of [`SMOITAL_EPOCH_YEAR`] to day 0 of this year.
+    fn epoch_sols(&self) -> Option<i64> {
+        let years = i64::from(self.year) - i64::from(SMOITAL_EPOCH_YEAR);
+        years.checked_mul(i64::from(self.total_days()))
+    }
+
+    /// Gregorian date on which the sol `sols` after [`SMOITAL_EPOCH`] begins.
+    fn gregorian_for_sol(sols: i64) -> Option<NaiveDate> {
+        let earth_days = (sols as f64 * SOL_LENGTH_SECONDS / 86_400.0).floor() as i64;
+        let days = Days::new(earth_days.unsigned_abs());
+        if earth_days < 0 {
+            SMOITAL_EPOCH.checked_sub_days(days)
+        } else {
+            SMOITAL_EPOCH.checked_add_days(days)
+        }
+    }
+
+    /// Fills a stack array with the offset of every day, avoiding heap allocation
+    /// (e.g. for flashing a precomputed table into ROM). Returns `None` unless
+    /// `D` equals [`SmoitalYear::total_days`].
+    pub fn offsets_fixed<const D: usize>(&self) -> Option<[FixedOffset; D]> {
+        if D != self.total_days() as usize {
+            return None;
+        }
+        Some(std::array::from_fn(|day| {
+            self.timezone_offset_for_day(day as u32)
+        }))
+    }
+
+    /// Run-length encode the daily offsets of the year as `(offset, run_length)` pairs.
+    ///
+    /// Consecutive days sharing the same offset collapse into a single entry,
+    /// giving a compact representation for transmitting schedules.
+    pub fn offset_rle(&self) -> Vec<(FixedOffset, u32)> {
+        let mut runs: Vec<(FixedOffset, u32)> = Vec::new();
+        for day in 0..self.total_days() {
+            let offset = self.timezone_offset_for_day(day);
+            match runs.last_mut() {
+                Some((last, len)) if *last == offset => *len += 1,
+                _ => runs.push((offset, 1)),
+            }
+        }
+        runs
+    }
+
     /// Convert a `SmoitalDate` to day-of-year and return the timezone offset.
     /// Invalid dates (wrong year or out-of-range day) return `None`.
     pub fn timezone_offset_for_date(&self, date: &SmoitalDate) -> Option<FixedOffset> {
@@ -31,43 +255,187 @@ impl<S: SmonthSchedule> SmoitalYear<S> {
     }
 
     /// Convert a `SmoitalDate` (Smonth is 0-indexed, Day is 1-indexed) to a day-of-year.
-    /// Returns `None` if the date does not belong to this year or the day exceeds the
-    /// length of the Smonth in the attached schedule.
+    /// Returns `None` if the date does not belong to this year, the Smonth is outside
+    /// the year, or the day exceeds the length of the Smonth in the attached schedule.
     pub fn day_of_year(&self, date: &SmoitalDate) -> Option<u32> {
-        if date.year != self.year || date.day == 0 {
+        if date.year != self.year {
             return None;
         }
 
-        let mut day_index: u32 = 0;
-        for smonth_idx in 0..date.smonth {
-            day_index += self.schedule.get_smonth_length(smonth_idx);
-        }
+        self.schedule.day_of_year(date.smonth, date.day)
+    }
+
+    /// Physical time from `a` to `b`, counting each calendar day as one sol.
+    ///
+    /// This is physical time: Smol days shorten the civil clock but not the sol,
+    /// so they do not shrink the result. Negative if `b` precedes `a`; `None` if
+    /// either date is invalid for this year.
+    pub fn duration_between(&self, a: &SmoitalDate, b: &SmoitalDate) -> Option<MarsDuration> {
+        let from = self.day_of_year(a)?;
+        let to = self.day_of_year(b)?;
+        Some(MarsDuration::from_sols(to as f64 - from as f64))
+    }
+
+    /// Signed number of calendar days from `a` to `b` (negative if `b` precedes
+    /// `a`). Returns `None` if either date is invalid for this year.
+    pub fn days_between(&self, a: &SmoitalDate, b: &SmoitalDate) -> Option<i64> {
+        let from = self.day_of_year(a)?;
+        let to = self.day_of_year(b)?;
+        Some(i64::from(to) - i64::from(from))
+    }
+
+    /// Civil time from the start of `a` to the start of `b`: each day counts its
+    /// civil length, so a Smol Day adds 24h 00m instead of 24h 40m. Compare
+    /// [`SmoitalYear::duration_between`], which counts physical sols. Negative if
+    /// `b` precedes `a`; `None` if either date is invalid for this year.
+    pub fn civil_duration_between(&self, a: &SmoitalDate, b: &SmoitalDate) -> Option<MarsDuration> {
+        let from = self.day_of_year(a)?;
+        let to = self.day_of_year(b)?;
+
+        let minutes: i64 = (from.min(to)..from.max(to))
+            .map(|day| self.schedule.day_length_minutes(day))
+            .sum();
+        let minutes = if to < from { -minutes } else { minutes };
+        Some(MarsDuration::from_earth_seconds(minutes as f64 * 60.0))
+    }
 
-        let smonth_len = self.schedule.get_smonth_length(date.smonth);
-        if date.day > smonth_len {
+    /// Move `date` by `n` whole Smonths, keeping the day-of-Smonth where possible.
+    /// Day 37 clamps to 36 when the target Smonth is short. Returns `None` if the
+    /// date is invalid for this year or the result would leave it.
+    pub fn add_smonths(&self, date: &SmoitalDate, n: i32) -> Option<SmoitalDate> {
+        self.day_of_year(date)?;
+
+        let smonth = u32::try_from(i64::from(date.smonth) + i64::from(n)).ok()?;
+        if smonth >= self.schedule.num_smonths() {
             return None;
         }
 
-        Some(day_index + (date.day - 1))
+        Some(SmoitalDate {
+            year: self.year,
+            smonth,
+            day: date.day.min(self.schedule.get_smonth_length(smonth)),
+        })
+    }
+
+    /// Move `date` by `days` calendar days (backwards if negative), rolling over
+    /// Smonth and year boundaries. Neighbouring years are assumed to share this
+    /// year's schedule. Returns `None` if the date is invalid for this year or
+    /// the resulting year overflows.
+    pub fn add_days(&self, date: &SmoitalDate, days: i64) -> Option<SmoitalDate> {
+        let total = i64::from(self.schedule.total_days());
+        let day = i64::from(self.day_of_year(date)?).checked_add(days)?;
+
+        let year = i64::from(self.year).checked_add(day.div_euclid(total))?;
+        let (smonth, day) = self.schedule.smonth_of_day(day.rem_euclid(total) as u32)?;
+
+        Some(SmoitalDate {
+            year: i32::try_from(year).ok()?,
+            smonth,
+            day,
+        })
     }
 
     /// Convert a day-of-year (0-indexed) into a `SmoitalDate` using the attached schedule.
+    /// Days past the end of the year roll into the following years, which are
+    /// assumed to share this schedule; use [`SmoitalYear::try_date_from_day`] to
+    /// reject them instead.
     pub fn date_from_day(&self, day_of_year: u32) -> SmoitalDate {
-        let mut remaining = day_of_year;
-        let mut smonth_idx = 0;
+        let total = self.schedule.total_days();
+        let years = i32::try_from(day_of_year / total).unwrap_or(i32::MAX);
+        let (smonth, day) = self
+            .schedule
+            .smonth_of_day(day_of_year % total)
+            .expect("day is within the year");
 
-        loop {
-            let smonth_len = self.schedule.get_smonth_length(smonth_idx);
-            if remaining < smonth_len {
-                return SmoitalDate {
+        SmoitalDate {
+            year: self.year.saturating_add(years),
+            smonth,
+            day,
+        }
+    }
+
+    /// Like [`SmoitalYear::date_from_day`], but returns `None` if the day is
+    /// past the end of the year.
+    pub fn try_date_from_day(&self, day_of_year: u32) -> Option<SmoitalDate> {
+        self.schedule
+            .smonth_of_day(day_of_year)
+            .map(|(smonth, day)| SmoitalDate {
+                year: self.year,
+                smonth,
+                day,
+            })
+    }
+
+    /// Self-test for custom schedules: checks that every date the schedule
+    /// considers valid survives a round trip through [`SmoitalYear::day_of_year`]
+    /// and [`SmoitalYear::try_date_from_day`], and that it is a Smol Day exactly
+    /// when it is the 37th day of a long Smonth. Returns the dates that fail.
+    pub fn validate_all(&self) -> Result<(), Vec<SmoitalDate>> {
+        let failures: Vec<SmoitalDate> = (0..self.schedule.num_smonths())
+            .flat_map(|smonth| {
+                (1..=self.schedule.get_smonth_length(smonth)).map(move |day| SmoitalDate {
                     year: self.year,
-                    smonth: smonth_idx,
-                    day: remaining + 1,
-                };
-            }
+                    smonth,
+                    day,
+                })
+            })
+            .filter(|date| match self.day_of_year(date) {
+                Some(day) => {
+                    self.try_date_from_day(day).as_ref() != Some(date)
+                        || self.schedule.is_smol_day(day) != (date.day == 37)
+                }
+                None => true,
+            })
+            .collect();
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
+        }
+    }
+
+    /// The first Smol Day strictly after `from_day`, or `None` if there are no
+    /// more this year.
+    pub fn next_smol_day(&self, from_day: u32) -> Option<u32> {
+        (from_day.checked_add(1)?..self.schedule.total_days())
+            .find(|&day| self.schedule.is_smol_day(day))
+    }
+
+    /// The last Smol Day strictly before `from_day`, or `None` if there is none
+    /// earlier this year.
+    pub fn prev_smol_day(&self, from_day: u32) -> Option<u32> {
+        (0..from_day.min(self.schedule.total_days()))
+            .rev()
+            .find(|&day| self.schedule.is_smol_day(day))
+    }
+
+    /// Countdown text for a clock-reset widget, e.g.
+    /// `Next clock reset in 2 sols (2090-S06-37)`, or `Clock reset today` on a
+    /// Smol Day. Uses [`SmoitalYear::next_smol_day`].
+    pub fn format_smol_countdown(&self, current_day: u32) -> String {
+        if current_day < self.total_days() && self.schedule.is_smol_day(current_day) {
+            return "Clock reset today".to_string();
+        }
+
+        match self
+            .next_smol_day(current_day)
+            .and_then(|day| Some((day - current_day, self.try_date_from_day(day)?)))
+        {
+            Some((1, date)) => format!("Next clock reset in 1 sol ({date})"),
+            Some((sols, date)) => format!("Next clock reset in {sols} sols ({date})"),
+            None => "No clock reset left this year".to_string(),
+        }
+    }
 
-            remaining -= smonth_len;
-            smonth_idx += 1;
+    /// Every date of the year in order, from Smonth 0 day 1 to the last day of
+    /// the final Smonth. Lazy and allocation-free.
+    pub fn dates(&self) -> Dates<'_, S> {
+        Dates {
+            year: self,
+            smonth: 0,
+            day: 1,
+            remaining: self.schedule.total_days(),
         }
     }
 
@@ -76,3 +444,106 @@ impl<S: SmonthSchedule> SmoitalYear<S> {
         &self.schedule
     }
 }
+
+/// Iterator over the dates of a [`SmoitalYear`], created by [`SmoitalYear::dates`].
+#[derive(Debug)]
+pub struct Dates<'a, S: SmonthSchedule> {
+    year: &'a SmoitalYear<S>,
+    smonth: u32,
+    day: u32,
+    remaining: u32,
+}
+
+impl<S: SmonthSchedule> Iterator for Dates<'_, S> {
+    type Item = SmoitalDate;
+
+    fn next(&mut self) -> Option<SmoitalDate> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let date = SmoitalDate {
+            year: self.year.year,
+            smonth: self.smonth,
+            day: self.day,
+        };
+        if self.day < self.year.schedule.get_smonth_length(self.smonth) {
+            self.day += 1;
+        } else {
+            self.smonth += 1;
+            self.day = 1;
+        }
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining as usize, Some(self.remaining as usize))
+    }
+}
+
+impl<S: SmonthSchedule> ExactSizeIterator for Dates<'_, S> {}
+
+#[cfg(feature = "viz")]
+impl<S: SmonthSchedule> SmoitalYear<S> {
+    /// Renders the year as a standalone SVG calendar: one row per Smonth, one
+    /// cell per day. Cells are shaded by UTC offset (hue sweeping from
+    /// -12:00 to +12:00) and Smol Days carry a dark `smol` circle marker.
+    pub fn to_svg(&self) -> String {
+        use crate::schedule::format_offset;
+        use std::fmt::Write;
+
+        const CELL: u32 = 16;
+        const LABEL: u32 = 40;
+
+        let rows = self.schedule.num_smonths();
+        let cols = (0..rows)
+            .map(|smonth| self.schedule.get_smonth_length(smonth))
+            .max()
+            .unwrap_or(0);
+        let width = LABEL + cols * CELL;
+        let height = rows * CELL;
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+        );
+        let _ = writeln!(svg, "<title>Smoital year {}</title>", self.year);
+
+        for smonth in 0..rows {
+            let y = smonth * CELL;
+            let _ = writeln!(
+                svg,
+                "<text x=\"0\" y=\"{}\" font-size=\"12\" font-family=\"monospace\">S{smonth:02}</text>",
+                y + CELL - 4
+            );
+        }
+
+        for day_of_year in 0..self.schedule.total_days() {
+            let Some(date) = self.try_date_from_day(day_of_year) else {
+                break;
+            };
+            let minutes = self.schedule.offset_minutes(day_of_year);
+            let hue = (minutes + 720) * 300 / 1440;
+            let x = LABEL + (date.day - 1) * CELL;
+            let y = date.smonth * CELL;
+
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{CELL}\" height=\"{CELL}\" fill=\"hsl({hue}, 70%, 65%)\" stroke=\"white\"><title>{date} UTC{}</title></rect>",
+                format_offset(self.timezone_offset_for_day(day_of_year))
+            );
+            if self.schedule.is_smol_day(day_of_year) {
+                let _ = writeln!(
+                    svg,
+                    "<circle class=\"smol\" cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"#222\"/>",
+                    x + CELL / 2,
+                    y + CELL / 2,
+                    CELL / 4
+                );
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
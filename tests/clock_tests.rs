@@ -0,0 +1,441 @@
+use chrono::{FixedOffset, TimeZone, Utc};
+use smoital::clock::{DisplayMode, SmoitalClock, SolClockCache};
+use std::time::Duration;
+
+#[test]
+fn tagged_format_round_trips_mode() {
+    let time = Utc.with_ymd_and_hms(2025, 1, 1, 23, 30, 0).unwrap();
+
+    let tagged = SmoitalClock::format_tagged(time, DisplayMode::XM);
+    assert_eq!(tagged, "12:10:00 XM X");
+    assert_eq!(
+        SmoitalClock::parse_tagged(&tagged),
+        Some((DisplayMode::XM, 23, 30, 0))
+    );
+
+    for mode in [
+        DisplayMode::Unoptimized,
+        DisplayMode::Overflowed,
+        DisplayMode::ExtendedMinutes,
+    ] {
+        let tagged = SmoitalClock::format_tagged(time, mode);
+        assert_eq!(SmoitalClock::parse_tagged(&tagged), Some((mode, 23, 30, 0)));
+    }
+
+    assert_eq!(SmoitalClock::parse_tagged("24:10:00 Q"), None);
+}
+
+#[test]
+fn mars_scaled_clock_ticks_at_mars_rate() {
+    // One Earth second is only ~0.973 Mars seconds.
+    assert_eq!(
+        SmoitalClock::format_mars_scaled(Duration::from_secs(1), DisplayMode::Overflowed),
+        "00:00:00"
+    );
+    // 3600 Earth seconds = 3503.68 Mars seconds.
+    assert_eq!(
+        SmoitalClock::format_mars_scaled(Duration::from_secs(3600), DisplayMode::Overflowed),
+        "00:58:23"
+    );
+
+    // The 24h 40m day is 86,424.24 Mars seconds, so the reading passes
+    // 24:00:00 just before the day ends and enters the extended window.
+    let last_second = Duration::from_secs(88_799);
+    assert_eq!(
+        SmoitalClock::format_mars_scaled(last_second, DisplayMode::Overflowed),
+        "24:00:23"
+    );
+    assert_eq!(
+        SmoitalClock::format_mars_scaled(last_second, DisplayMode::XM),
+        "12:00:23 XM"
+    );
+
+    // It wraps when the civil day ends, not after 88,800 Mars seconds.
+    assert_eq!(
+        SmoitalClock::format_mars_scaled(Duration::from_secs(88_800), DisplayMode::Overflowed),
+        "00:00:00"
+    );
+    assert_eq!(
+        SmoitalClock::format_mars_scaled(
+            Duration::from_secs(88_800 + 3600),
+            DisplayMode::Overflowed
+        ),
+        "00:58:23"
+    );
+}
+
+#[test]
+fn parsing_accepts_missing_seconds() {
+    assert_eq!(
+        SmoitalClock::parse_tagged("24:05 O"),
+        Some((DisplayMode::Overflowed, 23, 25, 0))
+    );
+    assert_eq!(
+        SmoitalClock::parse_tagged("12:05 XM X"),
+        Some((DisplayMode::XM, 23, 25, 0))
+    );
+    assert_eq!(
+        SmoitalClock::parse_tagged("23:65 E"),
+        Some((DisplayMode::ExtendedMinutes, 23, 25, 0))
+    );
+    assert_eq!(
+        SmoitalClock::parse_tagged("24:05:07.250 O"),
+        Some((DisplayMode::Overflowed, 23, 25, 7))
+    );
+
+    assert_eq!(SmoitalClock::parse_tagged("24:05:07:01 O"), None);
+    assert_eq!(SmoitalClock::parse_tagged("24 O"), None);
+    assert_eq!(SmoitalClock::parse_tagged("+1:05 U"), None);
+}
+
+#[test]
+fn hand_angles_follow_the_extended_dial() {
+    assert_eq!(SmoitalClock::hand_angles(0.0), (0.0, 0.0, 0.0));
+
+    let (hour, minute, second) = SmoitalClock::hand_angles(40.0 * 60.0);
+    assert!((minute - 240.0).abs() < 1e-9);
+    assert!((hour - 40.0 / 1480.0 * 360.0).abs() < 1e-9);
+    assert_eq!(second, 0.0);
+
+    // The full 24h 40m day brings every hand back to the top.
+    assert_eq!(SmoitalClock::hand_angles(1480.0 * 60.0), (0.0, 0.0, 0.0));
+
+    // One second before the wrap the minute hand is still at ~240°, so it
+    // jumps back to the top while the other hands move on smoothly.
+    let (hour, minute, second) = SmoitalClock::hand_angles(88_799.0);
+    assert!((minute - 239.9).abs() < 1e-9);
+    assert!((hour - 88_799.0 / 88_800.0 * 360.0).abs() < 1e-9);
+    assert!((second - 354.0).abs() < 1e-9);
+    assert_eq!(SmoitalClock::hand_angles(88_800.0).1, 0.0);
+}
+
+#[test]
+fn labeled_mode_flags_bonus_minutes() {
+    let bonus = Utc.with_ymd_and_hms(2025, 1, 1, 23, 25, 0).unwrap();
+    assert_eq!(
+        SmoitalClock::format(bonus, DisplayMode::Labeled),
+        "23:25:00 (+5 min)"
+    );
+
+    let regular = Utc.with_ymd_and_hms(2025, 1, 1, 23, 15, 0).unwrap();
+    assert_eq!(
+        SmoitalClock::format(regular, DisplayMode::Labeled),
+        "23:15:00"
+    );
+
+    let tagged = SmoitalClock::format_tagged(bonus, DisplayMode::Labeled);
+    assert_eq!(
+        SmoitalClock::parse_tagged(&tagged),
+        Some((DisplayMode::Labeled, 23, 25, 0))
+    );
+    assert_eq!(SmoitalClock::parse_tagged("23:25:00 (+9 min) L"), None);
+}
+
+#[test]
+fn midnight_can_render_as_end_of_previous_day() {
+    let midnight = Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap();
+
+    assert_eq!(
+        SmoitalClock::format_with_midnight(midnight, DisplayMode::Overflowed, true).as_deref(),
+        Some("24:40:00")
+    );
+    assert_eq!(
+        SmoitalClock::format_with_midnight(midnight, DisplayMode::Overflowed, false).as_deref(),
+        Some("00:00:00")
+    );
+
+    let just_after = Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 1).unwrap();
+    assert_eq!(
+        SmoitalClock::format_with_midnight(just_after, DisplayMode::Overflowed, true).as_deref(),
+        Some("00:00:01")
+    );
+
+    // Each mode writes the end of the extended window in its own notation,
+    // distinct from the window's first second (23:20 UTC).
+    let window_open = Utc.with_ymd_and_hms(2025, 1, 1, 23, 20, 0).unwrap();
+    for (mode, end_of_day) in [
+        (DisplayMode::Overflowed, "24:40:00"),
+        (DisplayMode::Labeled, "23:60:00 (+40 min)"),
+        (DisplayMode::ExtendedMinutes, "23:100:00"),
+        (DisplayMode::XM, "12:40:00 XM"),
+        (DisplayMode::TwelveHour, "11:100:00 PM"),
+    ] {
+        let text = SmoitalClock::format_with_midnight(midnight, mode, true).unwrap();
+        assert_eq!(text, end_of_day);
+        assert_ne!(text, SmoitalClock::format(window_open, mode));
+        assert_ne!(text, SmoitalClock::format(midnight, mode));
+        assert_eq!(
+            SmoitalClock::parse(&text, mode),
+            Some((24, 0, 0)),
+            "{mode:?}"
+        );
+
+        assert_eq!(
+            SmoitalClock::format_with_midnight(midnight, mode, false),
+            Some(SmoitalClock::format(midnight, mode))
+        );
+    }
+
+    // Unoptimized has no unambiguous end-of-day form.
+    assert_eq!(
+        SmoitalClock::format_with_midnight(midnight, DisplayMode::Unoptimized, true),
+        None
+    );
+    assert_eq!(
+        SmoitalClock::parse("24:40:01", DisplayMode::Overflowed),
+        None
+    );
+}
+
+#[test]
+fn sol_clock_cache_matches_format() {
+    for mode in [
+        DisplayMode::Overflowed,
+        DisplayMode::XM,
+        DisplayMode::Labeled,
+    ] {
+        let cache = SolClockCache::new(mode);
+
+        // Minute 1460 is 20 minutes into the extended window: 23:40 UTC.
+        let time = Utc.with_ymd_and_hms(2025, 1, 1, 23, 40, 0).unwrap();
+        assert_eq!(
+            cache.label_for_minute(1460),
+            SmoitalClock::format(time, mode)
+        );
+
+        let time = Utc.with_ymd_and_hms(2025, 1, 1, 9, 5, 0).unwrap();
+        assert_eq!(
+            cache.label_for_minute(545),
+            SmoitalClock::format(time, mode)
+        );
+
+        // At the window boundary the cache counts civil minutes, not UTC ones:
+        // minute 1440 opens the window that `format` opens at 23:20 UTC.
+        let at = |m| Utc.with_ymd_and_hms(2025, 1, 1, 23, m, 0).unwrap();
+        assert_eq!(
+            cache.label_for_minute(1399),
+            SmoitalClock::format(at(19), mode)
+        );
+        assert_eq!(
+            cache.label_for_minute(1440),
+            SmoitalClock::format(at(20), mode)
+        );
+        assert_eq!(
+            cache.label_for_minute(1479),
+            SmoitalClock::format(at(59), mode)
+        );
+        assert_eq!(cache.label_for_minute(1400), "23:20:00");
+        assert_ne!(
+            cache.label_for_minute(1400),
+            SmoitalClock::format(at(20), mode)
+        );
+    }
+}
+
+#[test]
+fn parse_inverts_format_for_every_mode() {
+    let modes = [
+        DisplayMode::Unoptimized,
+        DisplayMode::Overflowed,
+        DisplayMode::ExtendedMinutes,
+        DisplayMode::XM,
+        DisplayMode::Labeled,
+    ];
+    for mode in modes {
+        for (h, m, s) in [
+            (0, 0, 0),
+            (12, 0, 30),
+            (23, 19, 59),
+            (23, 20, 0),
+            (23, 59, 59),
+        ] {
+            let time = Utc.with_ymd_and_hms(2025, 1, 1, h, m, s).unwrap();
+            let text = SmoitalClock::format(time, mode);
+            assert_eq!(
+                SmoitalClock::parse(&text, mode),
+                Some((h, m, s)),
+                "{mode:?}: {text}"
+            );
+        }
+    }
+
+    assert_eq!(
+        SmoitalClock::parse("24:10:00", DisplayMode::Overflowed),
+        Some((23, 30, 0))
+    );
+    assert_eq!(
+        SmoitalClock::parse("23:75:00", DisplayMode::ExtendedMinutes),
+        Some((23, 35, 0))
+    );
+    assert_eq!(SmoitalClock::parse("24:10:00", DisplayMode::XM), None);
+}
+
+#[test]
+fn twelve_hour_mode_renders_am_pm() {
+    let mode = DisplayMode::TwelveHour;
+    let at = |h, m, s| Utc.with_ymd_and_hms(2025, 1, 1, h, m, s).unwrap();
+
+    assert_eq!(SmoitalClock::format(at(0, 0, 0), mode), "12:00:00 AM");
+    assert_eq!(SmoitalClock::format(at(12, 0, 0), mode), "12:00:00 PM");
+    assert_eq!(SmoitalClock::format(at(9, 5, 7), mode), "09:05:07 AM");
+    assert_eq!(SmoitalClock::format(at(23, 19, 59), mode), "11:19:59 PM");
+    // The extended window continues the 11 PM hour past minute 59.
+    assert_eq!(SmoitalClock::format(at(23, 20, 5), mode), "11:60:05 PM");
+    assert_eq!(SmoitalClock::format(at(23, 59, 0), mode), "11:99:00 PM");
+
+    for (h, m, s) in [
+        (0, 0, 0),
+        (12, 0, 0),
+        (11, 59, 59),
+        (23, 20, 5),
+        (23, 59, 0),
+    ] {
+        let tagged = SmoitalClock::format_tagged(at(h, m, s), mode);
+        assert_eq!(SmoitalClock::parse_tagged(&tagged), Some((mode, h, m, s)));
+    }
+    assert_eq!(SmoitalClock::parse("13:00:00 PM", mode), None);
+    assert_eq!(SmoitalClock::parse("10:60:00 PM", mode), None);
+    assert_eq!(SmoitalClock::parse("10:00:00", mode), None);
+    assert_eq!(
+        SmoitalClock::parse("10:00:00 PM", DisplayMode::Overflowed),
+        None
+    );
+
+    assert_eq!(
+        SolClockCache::new(mode).label_for_minute(1460),
+        "11:80:00 PM"
+    );
+}
+
+#[test]
+fn format_with_offset_detects_the_local_extended_window() {
+    let mode = DisplayMode::Overflowed;
+    let at = |h, m| Utc.with_ymd_and_hms(2025, 1, 1, h, m, 0).unwrap();
+
+    // Day 1 of a Smonth sits at UTC+12:00; day 2 at UTC+11:20.
+    let plus_12 = FixedOffset::east_opt(12 * 3600).unwrap();
+    let plus_11_20 = FixedOffset::east_opt(680 * 60).unwrap();
+    let minus_11_20 = FixedOffset::west_opt(680 * 60).unwrap();
+
+    assert_eq!(
+        SmoitalClock::format_with_offset(at(11, 30), plus_12, mode),
+        "24:10:00"
+    );
+    assert_eq!(
+        SmoitalClock::format_with_offset(at(12, 0), plus_11_20, mode),
+        "24:00:00"
+    );
+    assert_eq!(
+        SmoitalClock::format_with_offset(at(11, 59), plus_11_20, mode),
+        "23:19:00"
+    );
+    assert_eq!(
+        SmoitalClock::format_with_offset(at(10, 45), minus_11_20, mode),
+        "24:05:00"
+    );
+
+    // UTC 23:30 is no longer in the window once shifted.
+    assert_eq!(
+        SmoitalClock::format_with_offset(at(23, 30), plus_12, mode),
+        "11:30:00"
+    );
+    assert_eq!(
+        SmoitalClock::format_with_offset(at(23, 30), FixedOffset::east_opt(0).unwrap(), mode),
+        SmoitalClock::format(at(23, 30), mode)
+    );
+}
+
+#[test]
+fn format_with_precision_renders_fractional_seconds() {
+    let at = |h, m, s, nanos| {
+        Utc.with_ymd_and_hms(2025, 1, 1, h, m, s).unwrap() + chrono::Duration::nanoseconds(nanos)
+    };
+    let time = at(23, 30, 5, 123_456_789);
+
+    assert_eq!(
+        SmoitalClock::format_with_precision(time, DisplayMode::Overflowed, 3),
+        "24:10:05.123"
+    );
+    assert_eq!(
+        SmoitalClock::format_with_precision(time, DisplayMode::XM, 6),
+        "12:10:05.123457 XM"
+    );
+    assert_eq!(
+        SmoitalClock::format_with_precision(time, DisplayMode::Labeled, 3),
+        "23:30:05.123 (+10 min)"
+    );
+    assert_eq!(
+        SmoitalClock::format_with_precision(time, DisplayMode::Overflowed, 0),
+        SmoitalClock::format(time, DisplayMode::Overflowed)
+    );
+
+    // Rounding at the end of the extended window carries into the next day.
+    let last = at(23, 59, 59, 999_600_000);
+    assert_eq!(
+        SmoitalClock::format_with_precision(last, DisplayMode::Overflowed, 3),
+        "00:00:00.000"
+    );
+    assert_eq!(
+        SmoitalClock::format_with_precision(last, DisplayMode::Overflowed, 6),
+        "24:39:59.999600"
+    );
+    // Just before the window opens, rounding carries into it.
+    let edge = at(23, 19, 59, 999_999_000);
+    assert_eq!(
+        SmoitalClock::format_with_precision(edge, DisplayMode::ExtendedMinutes, 3),
+        "23:60:00.000"
+    );
+}
+
+#[test]
+fn custom_extended_window_shifts_the_boundary() {
+    let clock = SmoitalClock::with_extended_minutes(30).unwrap();
+    assert_eq!(clock.extended_minutes(), 30);
+    assert_eq!(SmoitalClock::default().extended_minutes(), 40);
+    assert_eq!(SmoitalClock::with_extended_minutes(61), None);
+
+    let at = |h, m| Utc.with_ymd_and_hms(2025, 1, 1, h, m, 0).unwrap();
+    assert_eq!(
+        clock.render(at(23, 25), DisplayMode::Overflowed),
+        "23:25:00"
+    );
+    assert_eq!(
+        clock.render(at(23, 30), DisplayMode::Overflowed),
+        "24:00:00"
+    );
+    assert_eq!(
+        clock.render(at(23, 59), DisplayMode::Overflowed),
+        "24:29:00"
+    );
+    assert_eq!(
+        clock.render(at(23, 59), DisplayMode::ExtendedMinutes),
+        "23:89:00"
+    );
+    assert_eq!(
+        clock.render(at(23, 45), DisplayMode::Labeled),
+        "23:45:00 (+15 min)"
+    );
+
+    // The default clock matches the associated formatter.
+    for mode in [
+        DisplayMode::Overflowed,
+        DisplayMode::XM,
+        DisplayMode::TwelveHour,
+    ] {
+        assert_eq!(
+            SmoitalClock::default().render(at(23, 25), mode),
+            SmoitalClock::format(at(23, 25), mode)
+        );
+    }
+}
+
+#[test]
+fn format_mtc_wraps_at_the_sol() {
+    assert_eq!(SmoitalClock::format_mtc(0.0), "00:00:00");
+    assert_eq!(SmoitalClock::format_mtc(86_400.0), "00:00:00");
+    assert_eq!(SmoitalClock::format_mtc(86_399.9), "23:59:59");
+    assert_eq!(SmoitalClock::format_mtc(45_296.5), "12:34:56");
+    assert_eq!(SmoitalClock::format_mtc(86_400.0 + 3_661.0), "01:01:01");
+    assert_eq!(SmoitalClock::format_mtc(-60.0), "23:59:00");
+    assert_eq!(SmoitalClock::format_mtc(f64::NAN), "00:00:00");
+}
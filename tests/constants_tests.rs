@@ -0,0 +1,11 @@
+use smoital::constants::required_smol_days;
+
+#[test]
+fn mars_year_needs_six_or_seven_smol_days() {
+    // One Mars year is ~686.98 Earth days.
+    let mars_year_minutes = (686.98 * 24.0 * 60.0) as i64;
+    let smol_days = required_smol_days(mars_year_minutes);
+    assert!((6..=7).contains(&smol_days), "got {smol_days}");
+
+    assert_eq!(required_smol_days(0), 0);
+}
@@ -0,0 +1,214 @@
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+use smoital::constants::SOL_LENGTH_SECONDS;
+use smoital::date::{SmoitalDate, SmoitalDateError, SmolLabel};
+use smoital::schedule::EquatorialSchedule;
+use smoital::year::SmoitalYear;
+
+#[test]
+fn same_day_of_year_ignores_year() {
+    let schedule = EquatorialSchedule::new();
+    let a = SmoitalDate {
+        year: 2030,
+        smonth: 6,
+        day: 37,
+    };
+    let b = SmoitalDate {
+        year: 2031,
+        smonth: 6,
+        day: 37,
+    };
+    let c = SmoitalDate {
+        year: 2030,
+        smonth: 7,
+        day: 1,
+    };
+
+    assert!(a.same_day_of_year(&b, &schedule));
+    assert!(!a.same_day_of_year(&c, &schedule));
+}
+
+#[test]
+fn smol_day_requires_a_long_smonth() {
+    let schedule = EquatorialSchedule::new();
+    let in_short_smonth = SmoitalDate {
+        year: 2030,
+        smonth: 0,
+        day: 37,
+    };
+    let in_long_smonth = SmoitalDate {
+        year: 2030,
+        smonth: 6,
+        day: 37,
+    };
+
+    assert!(in_short_smonth.is_smol_day());
+    assert!(!in_short_smonth.is_smol_day_in(&schedule));
+    assert!(in_long_smonth.is_smol_day_in(&schedule));
+}
+
+#[test]
+fn from_utc_maps_instants_into_the_year() {
+    let year = SmoitalYear::new(2030, EquatorialSchedule::new());
+    let epoch = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+
+    // Ten sols and one hour after the epoch lands on day 10.
+    let start = Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap();
+    let utc = start
+        + Duration::milliseconds((10.0 * SOL_LENGTH_SECONDS * 1000.0) as i64)
+        + Duration::hours(1);
+    assert_eq!(
+        SmoitalDate::from_utc(utc, epoch, &year),
+        Some(SmoitalDate {
+            year: 2030,
+            smonth: 0,
+            day: 11
+        })
+    );
+
+    let before = Utc.with_ymd_and_hms(2029, 12, 31, 23, 0, 0).unwrap();
+    assert_eq!(SmoitalDate::from_utc(before, epoch, &year), None);
+
+    // Past the end of the year, instants roll into the following years.
+    let after = Utc.with_ymd_and_hms(2033, 1, 1, 0, 0, 0).unwrap();
+    let rolled = SmoitalDate::from_utc(after, epoch, &year).unwrap();
+    assert_eq!(rolled.year, 2031);
+    assert_eq!(Some(rolled), year.add_days(&year.date_from_day(0), 1066));
+}
+
+#[test]
+fn packed_dates_round_trip() {
+    for date in [
+        SmoitalDate {
+            year: 2030,
+            smonth: 6,
+            day: 37,
+        },
+        SmoitalDate {
+            year: -12,
+            smonth: 0,
+            day: 1,
+        },
+    ] {
+        assert_eq!(
+            SmoitalDate::from_packed(date.to_packed().unwrap()),
+            Some(date)
+        );
+    }
+
+    // Fields that do not fit are rejected rather than truncated to a byte.
+    for (smonth, day) in [(6, 0), (6, 38), (6, 257), (18, 1), (259, 1)] {
+        let date = SmoitalDate {
+            year: 2030,
+            smonth,
+            day,
+        };
+        assert_eq!(date.to_packed(), None, "{date:?}");
+    }
+
+    let zero_day = (2030u64 << 32) | (6 << 8);
+    assert_eq!(SmoitalDate::from_packed(zero_day), None);
+    assert_eq!(SmoitalDate::from_packed(1 << 20 | 1), None);
+}
+
+#[test]
+fn dates_order_chronologically() {
+    let date = |year, smonth, day| SmoitalDate { year, smonth, day };
+
+    // Cross-year, cross-Smonth and same-Smonth comparisons.
+    assert!(date(2029, 17, 36) < date(2030, 0, 1));
+    assert!(date(-1, 5, 5) < date(0, 0, 1));
+    assert!(date(2030, 5, 37) < date(2030, 6, 1));
+    assert!(date(2030, 6, 2) < date(2030, 6, 10));
+    assert_eq!(
+        date(2030, 6, 2).cmp(&date(2030, 6, 2)),
+        std::cmp::Ordering::Equal
+    );
+
+    let mut dates = vec![date(2031, 0, 1), date(2030, 6, 37), date(2030, 6, 1)];
+    dates.sort();
+    assert_eq!(
+        dates,
+        vec![date(2030, 6, 1), date(2030, 6, 37), date(2031, 0, 1)]
+    );
+}
+
+#[test]
+fn parses_canonical_date_strings() {
+    let date = |year, smonth, day| SmoitalDate { year, smonth, day };
+
+    assert_eq!("2030-S06-37".parse(), Ok(date(2030, 6, 37)));
+    assert_eq!("2030-S6-7".parse(), Ok(date(2030, 6, 7)));
+    assert_eq!("0042-S000-001".parse(), Ok(date(42, 0, 1)));
+    assert_eq!("-12-S17-36".parse(), Ok(date(-12, 17, 36)));
+
+    let err = |s: &str| s.parse::<SmoitalDate>().unwrap_err();
+    assert_eq!(err("2030-S06-00"), SmoitalDateError::DayOutOfRange(0));
+    assert_eq!(err("2030-S06-38"), SmoitalDateError::DayOutOfRange(38));
+    assert_eq!(err("2030-S18-01"), SmoitalDateError::SmonthOutOfRange(18));
+    assert_eq!(err("2030/S06/01"), SmoitalDateError::InvalidFormat);
+    assert_eq!(err("2030-06-01"), SmoitalDateError::InvalidFormat);
+    assert_eq!(err("2030-S06-01-02"), SmoitalDateError::InvalidFormat);
+    assert_eq!(err("20x0-S06-01"), SmoitalDateError::InvalidNumber);
+    assert_eq!(err("2030-S+6-01"), SmoitalDateError::InvalidNumber);
+    assert_eq!(err("2030-S06-"), SmoitalDateError::InvalidNumber);
+}
+
+#[test]
+fn display_round_trips_through_from_str() {
+    let date = |year, smonth, day| SmoitalDate { year, smonth, day };
+
+    assert_eq!(date(2030, 6, 37).to_string(), "2030-S06-37");
+    assert_eq!(date(7, 0, 1).to_string(), "0007-S00-01");
+    assert_eq!(date(-12, 17, 5).to_string(), "-0012-S17-05");
+
+    for d in [
+        date(2030, 6, 37),
+        date(0, 0, 1),
+        date(-12, 17, 5),
+        date(i32::MIN, 3, 3),
+    ] {
+        assert_eq!(d.to_string().parse::<SmoitalDate>(), Ok(d));
+    }
+}
+
+#[test]
+fn checked_constructor_validates_ranges() {
+    assert_eq!(
+        SmoitalDate::new(2030, 6, 37),
+        Ok(SmoitalDate {
+            year: 2030,
+            smonth: 6,
+            day: 37
+        })
+    );
+    assert_eq!(
+        SmoitalDate::new(2030, 18, 1),
+        Err(SmoitalDateError::SmonthOutOfRange(18))
+    );
+    assert_eq!(
+        SmoitalDate::new(2030, 6, 0),
+        Err(SmoitalDateError::DayOutOfRange(0))
+    );
+    assert_eq!(
+        SmoitalDate::new(2030, 6, 99),
+        Err(SmoitalDateError::DayOutOfRange(99))
+    );
+}
+
+#[test]
+fn smol_marker_can_be_localized() {
+    let schedule = EquatorialSchedule::new();
+    let german = SmolLabel("kurz".to_string());
+    let smol = SmoitalDate::new(2030, 6, 37).unwrap();
+    let standard = SmoitalDate::new(2030, 6, 36).unwrap();
+
+    assert_eq!(
+        smol.format_localized(&schedule, &german),
+        "2030-S06-37 (kurz)"
+    );
+    assert_eq!(
+        smol.format_localized(&schedule, &SmolLabel::default()),
+        "2030-S06-37 (smol)"
+    );
+    assert_eq!(standard.format_localized(&schedule, &german), "2030-S06-36");
+}
@@ -1,4 +1,6 @@
-use smoital::duration::MarsDuration;
+use smoital::constants::SOL_LENGTH_SECONDS;
+use smoital::duration::{DurationError, FiniteMarsDuration, MarsDuration};
+use std::collections::BTreeSet;
 
 fn close_to(a: f64, b: f64) -> bool {
     let tolerance = 1e-9_f64.max(1e-9 * a.abs());
@@ -32,7 +34,446 @@ fn mars_seconds_are_preserved() {
 fn std_duration_matches_earth_seconds() {
     let duration = MarsDuration::from_mars_seconds(2.5);
     let earth_seconds = duration.as_earth_seconds();
-    let std_dur = duration.to_earth_duration();
+    let std_dur = duration.to_earth_duration().unwrap();
 
     assert!(close_to(std_dur.as_secs_f64(), earth_seconds));
 }
+
+#[test]
+fn try_into_std_reports_failure_reason() {
+    let ok = MarsDuration::from_mars_seconds(2.5).try_into_std().unwrap();
+    assert!(close_to(ok.as_secs_f64(), 2.5 * 1.02749125));
+
+    assert_eq!(
+        MarsDuration::from_mars_seconds(-1.0).try_into_std(),
+        Err(DurationError::Negative)
+    );
+    assert_eq!(
+        MarsDuration::from_mars_seconds(f64::NAN).try_into_std(),
+        Err(DurationError::NonFinite)
+    );
+    assert_eq!(
+        MarsDuration::from_mars_seconds(f64::INFINITY).try_into_std(),
+        Err(DurationError::NonFinite)
+    );
+    assert_eq!(
+        MarsDuration::from_mars_seconds(1e300).try_into_std(),
+        Err(DurationError::Overflow)
+    );
+}
+
+#[test]
+fn weighted_mean_normalizes_by_weight() {
+    let items = [
+        (MarsDuration::from_mars_seconds(100.0), 1.0),
+        (MarsDuration::from_mars_seconds(200.0), 3.0),
+    ];
+    let mean = MarsDuration::weighted_mean(&items).unwrap();
+    assert!(close_to(mean.as_mars_seconds(), 175.0));
+
+    assert!(MarsDuration::weighted_mean(&[]).is_none());
+    assert!(MarsDuration::weighted_mean(&[(MarsDuration::from_mars_seconds(1.0), 0.0)]).is_none());
+}
+
+#[test]
+fn scale_exp_halves_at_half_life() {
+    let half_life = MarsDuration::from_mars_seconds(1_000.0);
+
+    let one = MarsDuration::from_mars_seconds(1_000.0).scale_exp(half_life);
+    assert!(close_to(one.unwrap(), 0.5));
+
+    let two = MarsDuration::from_mars_seconds(2_000.0).scale_exp(half_life);
+    assert!(close_to(two.unwrap(), 0.25));
+
+    assert_eq!(
+        half_life.scale_exp(MarsDuration::from_mars_seconds(0.0)),
+        None
+    );
+}
+
+#[test]
+fn sol_clock_extended_uses_minutes_past_59() {
+    let regular = MarsDuration::from_earth_seconds(5.0 * 3600.0 + 61.5);
+    assert_eq!(regular.as_sol_clock_extended(), (0, 5, 1, 1));
+
+    // 24:10:00 into the second sol falls in the extended window.
+    let extended = MarsDuration::from_earth_seconds(SOL_LENGTH_SECONDS + 24.0 * 3600.0 + 600.5);
+    assert_eq!(extended.as_sol_clock_extended(), (1, 23, 70, 0));
+}
+
+#[test]
+fn approx_eq_tolerates_float_noise() {
+    let a = MarsDuration::from_mars_seconds(10.0);
+    let b = MarsDuration::from_mars_seconds(10.0 + 1e-12);
+
+    assert!(a.approx_eq(&b, 1e-9));
+    assert!(!a.approx_eq(&MarsDuration::from_mars_seconds(10.1), 1e-9));
+}
+
+#[test]
+fn mars_microseconds_round_trip() {
+    let duration = MarsDuration::from_mars_seconds(12_345.678_901_2);
+    let micros = duration.to_mars_microseconds();
+    assert_eq!(micros, 12_345_678_901);
+
+    let decoded = MarsDuration::from_mars_microseconds(micros);
+    assert!(decoded.approx_eq(&duration, 1e-6));
+    assert_eq!(duration.try_to_mars_microseconds(), Ok(micros));
+
+    assert_eq!(
+        MarsDuration::from_mars_seconds(1e300).try_to_mars_microseconds(),
+        Err(DurationError::Overflow)
+    );
+    assert_eq!(
+        MarsDuration::from_mars_seconds(f64::NAN).try_to_mars_microseconds(),
+        Err(DurationError::NonFinite)
+    );
+}
+
+#[test]
+fn debug_shows_mars_and_earth_seconds() {
+    let debug = format!("{:?}", MarsDuration::from_mars_seconds(123.456));
+    assert_eq!(debug, "MarsDuration(123.456 ms_s ≈ 126.85 es)");
+}
+
+#[test]
+fn earth_str_parses_bare_earth_seconds() {
+    let duration = MarsDuration::try_from_earth_str("5000").unwrap();
+    assert!(close_to(duration.as_earth_seconds(), 5_000.0));
+    assert!(close_to(duration.as_mars_seconds(), 5_000.0 / 1.02749125));
+
+    assert_eq!(
+        MarsDuration::try_from_earth_str(" 12.5 ").map(|d| d.as_earth_seconds()),
+        Ok(12.5)
+    );
+    assert_eq!(
+        MarsDuration::try_from_earth_str("abc"),
+        Err(DurationError::InvalidFormat)
+    );
+    assert_eq!(
+        MarsDuration::try_from_earth_str("inf"),
+        Err(DurationError::NonFinite)
+    );
+}
+
+#[test]
+fn met_format_pads_sols() {
+    let met = MarsDuration::from_mars_seconds(2.0 * 86_400.0 + 5.0 * 3600.0);
+    assert_eq!(met.format_met(3), "002:05:00:00");
+
+    let precise = MarsDuration::from_mars_seconds(86_400.0 + 12.0 * 60.0 + 33.0);
+    assert_eq!(precise.format_met(2), "01:00:12:33");
+
+    let prelaunch = MarsDuration::from_mars_seconds(-(3600.0 + 1.0));
+    assert_eq!(prelaunch.format_met(3), "-000:01:00:01");
+}
+
+#[test]
+fn finite_durations_are_totally_ordered() {
+    assert!(FiniteMarsDuration::new(MarsDuration::from_mars_seconds(f64::NAN)).is_none());
+    assert!(FiniteMarsDuration::new(MarsDuration::from_mars_seconds(f64::INFINITY)).is_none());
+
+    let set: BTreeSet<_> = [3.0, -1.0, 2.0, 3.0]
+        .into_iter()
+        .filter_map(|s| FiniteMarsDuration::new(MarsDuration::from_mars_seconds(s)))
+        .collect();
+    let sorted: Vec<f64> = set.iter().map(|d| d.get().as_mars_seconds()).collect();
+    assert_eq!(sorted, vec![-1.0, 2.0, 3.0]);
+
+    let zero = FiniteMarsDuration::new(MarsDuration::from_mars_seconds(0.0)).unwrap();
+    let neg_zero = FiniteMarsDuration::new(MarsDuration::from_mars_seconds(-0.0)).unwrap();
+    assert_eq!(zero, neg_zero);
+}
+
+#[test]
+fn time_of_sol_wraps_into_one_sol() {
+    let quarter = MarsDuration::from_sols(0.25);
+    assert!(
+        MarsDuration::from_sols(2.25)
+            .time_of_sol()
+            .approx_eq(&quarter, 1e-6)
+    );
+
+    // Negative durations wrap forward from the end of the sol.
+    let before_midnight = MarsDuration::from_sols(-0.25).time_of_sol();
+    assert!(before_midnight.approx_eq(&MarsDuration::from_sols(0.75), 1e-6));
+}
+
+#[test]
+fn bare_floats_convert_as_mars_seconds() {
+    let d: MarsDuration = 123.0.into();
+    assert_eq!(d, MarsDuration::from_mars_seconds(123.0));
+    assert_ne!(d, MarsDuration::from_earth_seconds(123.0));
+
+    let back: f64 = d.into();
+    assert_eq!(back, 123.0);
+    assert_eq!(f64::from(MarsDuration::from(-0.5)), -0.5);
+}
+
+#[test]
+fn batch_conversion_matches_element_wise() {
+    let earth = [0.0, 1.0, -42.5, 88_775.244, 1e9];
+    let mut mars = [0.0; 5];
+    MarsDuration::batch_from_earth_seconds(&earth, &mut mars);
+
+    for (&e, &m) in earth.iter().zip(&mars) {
+        assert_eq!(m, MarsDuration::from_earth_seconds(e).as_mars_seconds());
+    }
+
+    let mut back = [0.0; 5];
+    MarsDuration::batch_to_earth_seconds(&mars, &mut back);
+    for (&m, &e) in mars.iter().zip(&back) {
+        assert_eq!(e, MarsDuration::from_mars_seconds(m).as_earth_seconds());
+    }
+}
+
+#[test]
+fn sub_clamped_zero_absorbs_float_noise() {
+    let a = MarsDuration::from_mars_seconds(100.0);
+    let b = MarsDuration::from_mars_seconds(100.0 + 1e-12);
+
+    let diff = a.sub_clamped_zero(&b, 1e-9);
+    assert_eq!(diff, MarsDuration::ZERO);
+    assert!(diff.as_mars_seconds().is_sign_positive());
+
+    // Real differences pass through.
+    let c = MarsDuration::from_mars_seconds(99.0);
+    assert_eq!(c.sub_clamped_zero(&a, 1e-9).as_mars_seconds(), -1.0);
+}
+
+#[test]
+fn arithmetic_preserves_earth_seconds() {
+    let close = |d: MarsDuration, earth: f64| (d.as_earth_seconds() - earth).abs() < 1e-9;
+    let a = MarsDuration::from_earth_seconds(100.0);
+    let b = MarsDuration::from_earth_seconds(40.0);
+
+    assert!(close(a + b, 140.0));
+    assert!(close(a - b, 60.0));
+    assert!(close(b - a, -60.0));
+    assert!(close(a * 2.5, 250.0));
+    assert!(close(a / 4.0, 25.0));
+
+    let mut total = MarsDuration::ZERO;
+    for _ in 0..10 {
+        total += MarsDuration::from_earth_seconds(1.5);
+    }
+    assert!(close(total, 15.0));
+    total -= b;
+    assert!(close(total, -25.0));
+    total *= -2.0;
+    assert!(close(total, 50.0));
+    total /= 5.0;
+    assert!(close(total, 10.0));
+}
+
+#[test]
+fn dual_format_carries_both_representations() {
+    let d = MarsDuration::from_mars_seconds(1.5 * 86_400.0);
+    let dual = d.format_dual();
+    assert_eq!(dual, "129600.000s (mars) = 1sol 12:00:00");
+    assert!(dual.contains("129600.000s"));
+    assert!(dual.contains("1sol 12:00:00"));
+
+    assert_eq!(
+        MarsDuration::from_mars_seconds(-90.5).format_dual(),
+        "-90.500s (mars) = -0sol 00:01:30"
+    );
+}
+
+#[test]
+fn display_and_parse_round_trip() {
+    for secs in [0.0, 123.456, -2.5, 1e-9, 86_400.0 * 668.6] {
+        let d = MarsDuration::from_mars_seconds(secs);
+        let text = d.to_string();
+        assert!(text.ends_with("ms_s"), "{text}");
+        assert_eq!(text.parse::<MarsDuration>(), Ok(d));
+    }
+    assert_eq!(
+        format!("{:.2}", MarsDuration::from_mars_seconds(123.456)),
+        "123.46ms_s"
+    );
+
+    // Earth-second and explicit Mars-second suffixes.
+    let sol: MarsDuration = "88775.244es".parse().unwrap();
+    assert!((sol.as_mars_seconds() - 86_400.0).abs() < 1e-6);
+    assert_eq!(
+        " 2.5ms_s ".parse(),
+        Ok(MarsDuration::from_mars_seconds(2.5))
+    );
+
+    for bad in ["", "ms_s", "12", "12s", "2.5ms", "abcms_s", "1.0.0es"] {
+        assert_eq!(
+            bad.parse::<MarsDuration>(),
+            Err(DurationError::InvalidFormat),
+            "{bad:?}"
+        );
+    }
+    for bad in ["NaNms_s", "infes", "-infms_s"] {
+        assert_eq!(
+            bad.parse::<MarsDuration>(),
+            Err(DurationError::NonFinite),
+            "{bad:?}"
+        );
+    }
+}
+
+#[test]
+fn chrono_earth_conversion_round_trips() {
+    let cases = [
+        chrono::Duration::zero(),
+        chrono::Duration::seconds(88_775),
+        chrono::Duration::milliseconds(1_500),
+        chrono::Duration::nanoseconds(-2_250_000_001),
+    ];
+    for d in cases {
+        let mars = MarsDuration::from_chrono_earth(d);
+        assert_eq!(mars.to_chrono_earth(), d, "{d}");
+    }
+
+    let half = MarsDuration::from_earth_seconds(0.5);
+    assert_eq!(half.to_chrono_earth(), chrono::Duration::milliseconds(500));
+    let one_sol = MarsDuration::from_mars_seconds(86_400.0);
+    assert_eq!(
+        one_sol.to_chrono_earth(),
+        chrono::Duration::milliseconds(88_775_244)
+    );
+
+    assert_eq!(
+        MarsDuration::from_mars_seconds(1e300).to_chrono_earth(),
+        chrono::Duration::MAX
+    );
+    assert_eq!(
+        MarsDuration::from_mars_seconds(-1e300).to_chrono_earth(),
+        chrono::Duration::MIN
+    );
+    assert_eq!(
+        MarsDuration::from_mars_seconds(f64::NAN).to_chrono_earth(),
+        chrono::Duration::zero()
+    );
+}
+
+#[test]
+fn rounds_to_whole_smol_corrections() {
+    let correction = 40.0 * 60.0;
+    let rounded = |n: f64| {
+        MarsDuration::from_mars_seconds(n * correction)
+            .round_to_smol_correction()
+            .as_mars_seconds()
+            / correction
+    };
+
+    assert_eq!(rounded(1.5), 2.0);
+    assert_eq!(rounded(1.52), 2.0);
+    assert_eq!(rounded(1.49), 1.0);
+    assert_eq!(rounded(0.2), 0.0);
+    assert_eq!(rounded(-2.6), -3.0);
+}
+
+#[test]
+fn negative_durations_do_not_convert_to_std() {
+    let negative = MarsDuration::from_earth_seconds(-90.0);
+    assert!(negative.is_negative());
+    assert_eq!(negative.to_earth_duration(), None);
+
+    let positive = negative.abs();
+    assert!(!positive.is_negative());
+    let std_dur = positive.to_earth_duration().unwrap();
+    assert!(close_to(std_dur.as_secs_f64(), 90.0));
+
+    assert!(!MarsDuration::from_mars_seconds(-0.0).is_negative());
+    assert_eq!(
+        MarsDuration::from_mars_seconds(f64::NAN).to_earth_duration(),
+        None
+    );
+}
+
+#[test]
+fn one_sol_is_sol_length_earth_seconds() {
+    let sol = MarsDuration::from_sols(1.0);
+    assert!(close_to(sol.as_earth_seconds(), SOL_LENGTH_SECONDS));
+    assert!(close_to(sol.as_mars_seconds(), 86_400.0));
+    assert!(close_to(sol.as_sols(), 1.0));
+
+    let half = MarsDuration::from_sols(0.5);
+    assert!(close_to(half.as_earth_seconds(), SOL_LENGTH_SECONDS / 2.0));
+    assert!(close_to(
+        MarsDuration::from_earth_seconds(SOL_LENGTH_SECONDS * 3.0).as_sols(),
+        3.0
+    ));
+}
+
+#[test]
+fn cmp_total_sorts_with_negatives_and_nan() {
+    let secs = [3.0, -1.5, f64::NAN, 0.0, -40.0, 12.25];
+    let mut durations: Vec<MarsDuration> = secs
+        .iter()
+        .map(|&s| MarsDuration::from_mars_seconds(s))
+        .collect();
+    durations.sort_by(MarsDuration::cmp_total);
+
+    let sorted: Vec<f64> = durations.iter().map(|d| d.as_mars_seconds()).collect();
+    assert_eq!(&sorted[..5], &[-40.0, -1.5, 0.0, 3.0, 12.25]);
+    assert!(sorted[5].is_nan());
+
+    let finite = durations[..5].iter().copied();
+    let max = finite.max_by(MarsDuration::cmp_total).unwrap();
+    assert_eq!(max.as_mars_seconds(), 12.25);
+}
+
+#[test]
+fn met_strings_round_trip() {
+    for secs in [
+        0.0,
+        2.0 * 86_400.0 + 5.0 * 3600.0 + 12.0 * 60.0 + 33.0,
+        -90.0,
+    ] {
+        let d = MarsDuration::from_mars_seconds(secs);
+        let met = d.format_met(3);
+        assert_eq!(MarsDuration::parse_met(&met), Ok(d), "{met}");
+    }
+    assert_eq!(
+        MarsDuration::parse_met("002:05:12:33")
+            .unwrap()
+            .format_met(3),
+        "002:05:12:33"
+    );
+    assert_eq!(
+        MarsDuration::parse_met("1234:00:00:00")
+            .unwrap()
+            .as_mars_seconds(),
+        1234.0 * 86_400.0
+    );
+
+    for bad in [
+        "002:24:00:00",
+        "002:05:60:00",
+        "002:05:12:60",
+        "002:05:12",
+        "002:05:12:33:00",
+        "2:+5:12:33",
+        "",
+    ] {
+        assert_eq!(
+            MarsDuration::parse_met(bad),
+            Err(DurationError::InvalidFormat),
+            "{bad:?}"
+        );
+    }
+}
+
+#[test]
+fn durations_sum() {
+    let earth = [1.5, 60.0, -3.25, 86_400.0, 0.001];
+    let durations: Vec<MarsDuration> = earth
+        .iter()
+        .map(|&e| MarsDuration::from_earth_seconds(e))
+        .collect();
+
+    let by_ref: MarsDuration = durations.iter().sum();
+    let by_value: MarsDuration = durations.into_iter().sum();
+    assert!(close_to(by_ref.as_earth_seconds(), earth.iter().sum()));
+    assert_eq!(by_ref, by_value);
+
+    let empty: [MarsDuration; 0] = [];
+    assert_eq!(empty.iter().sum::<MarsDuration>(), MarsDuration::ZERO);
+}
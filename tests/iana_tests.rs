@@ -0,0 +1,69 @@
+use smoital::date::SmoitalDate;
+use smoital::iana::generate_year_rules;
+use smoital::schedule::{EquatorialSchedule, HeuristicSchedule, SmonthSchedule};
+use smoital::year::SmoitalYear;
+
+/// A twelve-Smonth year with two long Smonths: 12 * 36 + 2 = 434 days.
+struct CustomSchedule;
+
+impl SmonthSchedule for CustomSchedule {
+    fn is_smol_smonth(&self, smonth_index: u32) -> bool {
+        smonth_index == 3 || smonth_index == 5
+    }
+
+    fn num_smonths(&self) -> u32 {
+        12
+    }
+}
+
+#[test]
+fn year_length_follows_the_schedule_everywhere() {
+    let schedule = CustomSchedule;
+    assert_eq!(schedule.total_days(), 434);
+
+    let rules = generate_year_rules(2030, &schedule);
+    assert_eq!(rules.len(), 434);
+    assert_eq!(rules[433], "Rule 2030 Smoital only Day433 24:00 -680");
+
+    let year = SmoitalYear::new(2030, CustomSchedule);
+    let iterated = (0..)
+        .take_while(|&d| year.try_date_from_day(d).is_some())
+        .count();
+    assert_eq!(iterated, 434);
+
+    let last = SmoitalDate {
+        year: 2030,
+        smonth: 11,
+        day: 36,
+    };
+    assert_eq!(year.date_from_day(433), last.clone());
+    assert_eq!(year.day_of_year(&last), Some(433));
+    assert_eq!(
+        year.day_of_year(&SmoitalDate {
+            year: 2030,
+            smonth: 12,
+            day: 1
+        }),
+        None
+    );
+    assert_eq!(
+        year.offset_rle().iter().map(|&(_, len)| len).sum::<u32>(),
+        434
+    );
+}
+
+#[test]
+fn builtin_schedules_emit_one_rule_per_day() {
+    let equatorial = EquatorialSchedule::new();
+    assert_eq!(
+        generate_year_rules(2030, &equatorial).len(),
+        equatorial.total_days() as usize
+    );
+
+    let heuristic = HeuristicSchedule::new(2030, 0.0);
+    let rules = generate_year_rules(2030, &heuristic);
+    assert_eq!(rules.len(), heuristic.total_days() as usize);
+    // 18 Smonths of 36 days, six of them lengthened by a Smol Day.
+    assert_eq!(rules.len(), 18 * 36 + 6);
+    assert_eq!(rules[216], "Rule 2030 Smoital only Day216 24:00 -720");
+}
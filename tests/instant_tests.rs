@@ -0,0 +1,153 @@
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+use smoital::constants::SOL_LENGTH_SECONDS;
+use smoital::date::SmoitalDate;
+use smoital::duration::MarsDuration;
+use smoital::instant::SmoitalInstant;
+use smoital::schedule::EquatorialSchedule;
+use smoital::year::SmoitalYear;
+
+#[test]
+fn smol_day_rolls_over_sooner_than_standard_day() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    let fifty_minutes = MarsDuration::from_earth_seconds(50.0 * 60.0);
+    let half_past_23 = 23.0 * 3600.0 + 30.0 * 60.0;
+
+    // 23:30 on the Smol Day is 30 minutes before its 24:00 end.
+    let smol = SmoitalInstant::new(year.date_from_day(252), half_past_23);
+    let after_smol = smol.add_duration(fifty_minutes, &year).unwrap();
+    assert_eq!(after_smol.date, year.date_from_day(253));
+    assert!((after_smol.sol_seconds - 20.0 * 60.0).abs() < 1e-6);
+
+    // The same time on a standard day still has 70 minutes left before 24:40.
+    let standard = SmoitalInstant::new(year.date_from_day(251), half_past_23);
+    let after_standard = standard.add_duration(fifty_minutes, &year).unwrap();
+    assert_eq!(after_standard.date, year.date_from_day(251));
+    assert!((after_standard.sol_seconds - (24.0 * 3600.0 + 20.0 * 60.0)).abs() < 1e-6);
+
+    // Rewinding undoes the rollover.
+    let back = after_smol
+        .add_duration(MarsDuration::from_earth_seconds(-50.0 * 60.0), &year)
+        .unwrap();
+    assert_eq!(
+        back.date,
+        SmoitalDate {
+            year: 2090,
+            smonth: 6,
+            day: 37
+        }
+    );
+    assert!((back.sol_seconds - half_past_23).abs() < 1e-6);
+}
+
+#[test]
+fn add_duration_past_the_year_returns_none_promptly() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    let start = SmoitalInstant::new(year.date_from_day(0), 0.0);
+
+    for secs in [1e11, 1e15, 1e300, -1e300] {
+        let duration = MarsDuration::from_earth_seconds(secs);
+        assert_eq!(start.add_duration(duration, &year), None);
+    }
+    let last = SmoitalInstant::new(year.date_from_day(654), 0.0);
+    assert_eq!(last.add_duration(MarsDuration::from_sols(2.0), &year), None);
+}
+
+#[test]
+fn duration_since_counts_smol_day_as_24_hours() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    let noon = 12.0 * 3600.0;
+
+    // Day 251 (standard), 252 (Smol Day), 253 (standard).
+    let before = SmoitalInstant::new(year.date_from_day(251), noon);
+    let after = SmoitalInstant::new(year.date_from_day(253), noon);
+
+    // Noon to noon spans half of each side plus the whole Smol Day:
+    // 24h 40m (day 251) + 24h 00m (day 252) of civil time.
+    let elapsed = after.duration_since(&before, &year).unwrap();
+    let expected = (24.0 * 60.0 + 40.0 + 24.0 * 60.0) * 60.0;
+    assert!((elapsed.as_earth_seconds() - expected).abs() < 1e-6);
+
+    let via_sub = (after.in_year(&year).unwrap() - before.in_year(&year).unwrap()).unwrap();
+    assert!(via_sub.approx_eq(&elapsed, 1e-9));
+    let reversed = (before.in_year(&year).unwrap() - after.in_year(&year).unwrap()).unwrap();
+    assert!((reversed.as_earth_seconds() + expected).abs() < 1e-6);
+
+    // It inverts add_duration.
+    let back = before.add_duration(elapsed, &year).unwrap();
+    assert_eq!(back.date, after.date);
+    assert!((back.sol_seconds - noon).abs() < 1e-6);
+}
+
+#[test]
+fn timestamps_round_trip_through_parse() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+
+    // A standard day in its extended window, at UTC+11:20.
+    let standard = SmoitalInstant::new(year.date_from_day(1), 24.0 * 3600.0 + 605.0);
+    let text = standard.in_year(&year).unwrap().to_string();
+    assert_eq!(text, "2090-S00-02 24:10:05 UTC+11:20");
+    assert_eq!(SmoitalInstant::parse(&text, &year), Some(standard));
+
+    // A Smol Day, including its terminal 24:00:00.
+    let smol = SmoitalInstant::new(year.date_from_day(252), 23.0 * 3600.0);
+    let text = smol.in_year(&year).unwrap().to_string();
+    assert_eq!(text, "2090-S06-37 23:00:00 UTC-12:00");
+    assert_eq!(SmoitalInstant::parse(&text, &year), Some(smol));
+
+    let end = SmoitalInstant::parse("2090-S06-37 24:00:00 UTC-12:00", &year).unwrap();
+    assert_eq!(end.sol_seconds, 86_400.0);
+    assert_eq!(
+        SmoitalInstant::parse("2090-S06-37 24:00:01 UTC-12:00", &year),
+        None
+    );
+
+    // The offset must match the schedule, and the date must be in the year.
+    assert_eq!(
+        SmoitalInstant::parse("2090-S06-37 12:00:00 UTC+12:00", &year),
+        None
+    );
+    assert_eq!(
+        SmoitalInstant::parse("2091-S06-37 12:00:00 UTC-12:00", &year),
+        None
+    );
+    assert_eq!(
+        SmoitalInstant::parse("2090-S06-37 12:0:00 UTC-12:00", &year),
+        None
+    );
+}
+
+#[test]
+fn in_year_rejects_dates_outside_the_year() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    let elsewhere = SmoitalInstant::new(SmoitalDate::new(2091, 0, 1).unwrap(), 0.0);
+    assert!(elsewhere.in_year(&year).is_none());
+
+    let here = SmoitalInstant::new(year.date_from_day(0), 0.0);
+    let bound = here.in_year(&year).unwrap();
+    assert_eq!(bound.instant(), &here);
+    assert_eq!(bound.to_string(), "2090-S00-01 00:00:00 UTC+12:00");
+}
+
+#[test]
+fn now_from_maps_utc_onto_the_civil_day() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    let epoch = NaiveDate::from_ymd_opt(2090, 1, 1).unwrap();
+    let start = Utc.with_ymd_and_hms(2090, 1, 1, 0, 0, 0).unwrap();
+    let after_sols =
+        |sols: f64| start + Duration::milliseconds((sols * SOL_LENGTH_SECONDS * 1000.0) as i64);
+
+    // Halfway through day 10, a standard 24h 40m day: 12:20 civil time.
+    let instant = SmoitalInstant::now_from(after_sols(10.5), epoch, &year).unwrap();
+    assert_eq!(instant.date, SmoitalDate::new(2090, 0, 11).unwrap());
+    assert!((instant.sol_seconds - (12.0 * 3600.0 + 20.0 * 60.0)).abs() < 0.01);
+
+    // Halfway through the Smol Day at 252, which only lasts 24h 00m.
+    let smol = SmoitalInstant::now_from(after_sols(252.5), epoch, &year).unwrap();
+    assert_eq!(smol.date, SmoitalDate::new(2090, 6, 37).unwrap());
+    assert!((smol.sol_seconds - 12.0 * 3600.0).abs() < 0.01);
+
+    assert_eq!(
+        SmoitalInstant::now_from(start - Duration::seconds(1), epoch, &year),
+        None
+    );
+}
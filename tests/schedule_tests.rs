@@ -1,4 +1,9 @@
-use smoital::schedule::{EquatorialSchedule, HeuristicSchedule, SmonthSchedule};
+use smoital::constants::SOL_LENGTH_SECONDS;
+use smoital::schedule::{
+    CustomSchedule, EquatorialSchedule, HeuristicSchedule, PolarSchedule, ScheduleError,
+    SlideDirection, SmoitalConfig, SmonthSchedule,
+};
+use smoital::year::SmoitalYear;
 
 fn assert_offset_seconds(schedule: &impl SmonthSchedule, day: u32, expected_secs: i32) {
     assert_eq!(
@@ -61,4 +66,496 @@ fn heuristic_schedule_marks_smol_days_and_wraps_offsets() {
     // Immediately after smol days the offset should re-align to +00:00.
     assert_offset_seconds(&sched, 217, 0);
     assert_offset_seconds(&sched, 254, 0);
+
+    // Each Smol Day is the 37th day of a long Smonth.
+    let long: Vec<u32> = (0..18).filter(|&idx| sched.is_smol_smonth(idx)).collect();
+    assert_eq!(long, [5, 6, 8, 9, 11, 13]);
+    for (smonth, day) in long.into_iter().zip(expected_smol_days) {
+        assert_eq!(sched.day_of_year(smonth, 37), Some(day));
+    }
+}
+
+#[test]
+fn heuristic_slide_follows_configured_grid() {
+    let config = SmoitalConfig {
+        slide_minutes: 30,
+        ..SmoitalConfig::default()
+    };
+    let sched = HeuristicSchedule::with_config(2030, 0.0, config).unwrap();
+    let default = HeuristicSchedule::new(2030, 0.0);
+
+    assert_offset_seconds(&sched, 0, 0);
+    assert_offset_seconds(&sched, 1, -30 * 60);
+    assert_offset_seconds(&sched, 2, -60 * 60);
+
+    assert_offset_seconds(&default, 1, -40 * 60);
+    assert_offset_seconds(&default, 2, -80 * 60);
+
+    // Day lengths, drift and corrections follow the configured slide.
+    assert_eq!(sched.slide_minutes(), 30);
+    assert_eq!(default.slide_minutes(), 40);
+    assert_eq!(sched.day_length_minutes(0), 24 * 60 + 30);
+    assert_eq!(default.day_length_minutes(0), 24 * 60 + 40);
+    let smol = sched.smol_days()[0];
+    assert_eq!(sched.day_length_minutes(smol), 24 * 60);
+
+    let drift = sched.civil_vs_physical_drift(1);
+    assert!((drift - ((24.0 * 60.0 + 30.0) * 60.0 - SOL_LENGTH_SECONDS)).abs() < 1e-9);
+    assert_eq!(
+        sched.total_smol_correction_minutes(sched.total_days()),
+        30 * sched.smol_days().len() as i64
+    );
+}
+
+#[test]
+fn equatorial_matches_paper_offsets() {
+    assert_eq!(EquatorialSchedule::new().verify_against_paper(), Ok(()));
+}
+
+#[test]
+fn heuristic_rejects_parameters_without_smol_days() {
+    // A huge C2 pushes the first long Smonth far past the end of the year.
+    let config = SmoitalConfig {
+        c2: 100.0,
+        ..SmoitalConfig::default()
+    };
+    assert_eq!(
+        HeuristicSchedule::with_config(2030, 0.0, config).err(),
+        Some(ScheduleError::NoSmolDays)
+    );
+
+    // A zero or negative slide would collapse the rounding grid.
+    for slide_minutes in [0, -40] {
+        let config = SmoitalConfig {
+            slide_minutes,
+            ..SmoitalConfig::default()
+        };
+        assert_eq!(
+            HeuristicSchedule::with_config(2030, 0.0, config).err(),
+            Some(ScheduleError::InvalidSlide(slide_minutes))
+        );
+    }
+
+    // A late first long Smonth keeps every Smol Day that fits in the year,
+    // up to the last day of the final Smonth.
+    let late = SmoitalConfig {
+        c2: 8.5,
+        ..SmoitalConfig::default()
+    };
+    let sched = HeuristicSchedule::with_config(2030, 0.0, late).unwrap();
+    let long: Vec<u32> = (0..18).filter(|&idx| sched.is_smol_smonth(idx)).collect();
+    assert_eq!(long, [9, 10, 12, 13, 15, 17]);
+    assert_eq!(sched.total_days(), 654);
+    assert_eq!(sched.smol_days().last(), Some(&653));
+}
+
+#[test]
+fn offset_minutes_matches_fixed_offset() {
+    let sched = EquatorialSchedule::new();
+    assert_eq!(sched.offset_minutes(252), -720);
+    assert_eq!(sched.offset_minutes(0), 720);
+
+    let heuristic = HeuristicSchedule::new(2030, 0.0);
+    for day in [0, 1, 215, 216, 217] {
+        assert_eq!(
+            heuristic.offset_minutes(day) * 60,
+            heuristic.get_timezone_offset(day).local_minus_utc()
+        );
+    }
+}
+
+struct PartialSmolSchedule;
+
+impl SmonthSchedule for PartialSmolSchedule {
+    fn is_smol_smonth(&self, smonth_index: u32) -> bool {
+        smonth_index == 1
+    }
+
+    fn partial_smol_days(&self) -> Vec<u32> {
+        vec![5, 36]
+    }
+}
+
+#[test]
+fn partial_smol_day_halves_the_slide() {
+    let sched = PartialSmolSchedule;
+
+    assert_eq!(sched.offset_minutes(4), 560);
+    assert_eq!(sched.offset_minutes(5), 520);
+    // The partial Smol Day only slides 20 minutes, and the days after it stay
+    // 20 minutes above the formula, sliding the usual 40 from there.
+    assert_eq!(sched.offset_minutes(6), 500);
+    assert_offset_seconds(&sched, 6, 500 * 60);
+    assert_eq!(sched.offset_minutes(7), 460);
+    assert_eq!(sched.day_length_minutes(5), 24 * 60 + 20);
+    assert_eq!(sched.day_length_minutes(6), 24 * 60 + 40);
+
+    // The carry crosses into the next Smonth, wrapping past +12:00, and a
+    // second partial day adds to it.
+    assert_eq!(sched.offset_minutes(35), -660);
+    assert_eq!(sched.offset_minutes(36), -700);
+    assert_eq!(sched.offset_minutes(37), 720);
+
+    // The Smol Day at the end of Smonth 1 stays pinned and resets the carry.
+    assert_eq!(sched.offset_minutes(72), -720);
+    assert_eq!(sched.offset_minutes(73), 720);
+
+    // The shorter day shows up in the civil clock's drift.
+    let standard_step = (24.0 * 60.0 + 40.0) * 60.0 - SOL_LENGTH_SECONDS;
+    assert!((sched.civil_vs_physical_drift(6) - (6.0 * standard_step - 20.0 * 60.0)).abs() < 1e-6);
+
+    // Built-in schedules define no partial Smol Days.
+    assert!(EquatorialSchedule::new().partial_smol_days().is_empty());
+}
+
+#[test]
+fn days_until_wrap_counts_to_next_reset() {
+    let sched = EquatorialSchedule::new();
+
+    // Day 35 is the last day of the first Smonth; day 36 restarts at +12:00.
+    assert_eq!(sched.days_until_wrap(30), Some(6));
+    assert_eq!(sched.days_until_wrap(35), Some(1));
+
+    // Across the Smol Day at 252.
+    assert_eq!(sched.days_until_wrap(250), Some(3));
+    assert_eq!(sched.days_until_wrap(252), Some(1));
+
+    // Day 36 sits at -11:40 after the carry; day 37 wraps back to +12:00.
+    assert_eq!(PartialSmolSchedule.days_until_wrap(0), Some(37));
+}
+
+#[test]
+fn quiet_period_is_the_longest_standard_run() {
+    assert_eq!(EquatorialSchedule::new().quiet_period(), Some(0..216));
+    assert_eq!(
+        HeuristicSchedule::new(2030, 0.0).quiet_period(),
+        Some(0..216)
+    );
+}
+
+#[test]
+fn iso_designators_follow_daily_offsets() {
+    let designators = EquatorialSchedule::new().iso_designators(253);
+
+    assert_eq!(designators.len(), 253);
+    assert_eq!(designators[0], "+12:00");
+    assert_eq!(designators[1], "+11:20");
+    assert_eq!(designators[18], "+00:00");
+    assert_eq!(designators[19], "-00:40");
+    assert_eq!(designators[252], "-12:00");
+}
+
+#[test]
+fn start_offset_shifts_the_progression() {
+    let shifted = EquatorialSchedule::with_start_offset(0);
+
+    assert_offset_seconds(&shifted, 0, 0);
+    assert_offset_seconds(&shifted, 1, -40 * 60);
+    assert_offset_seconds(&shifted, 17, -680 * 60);
+    // The slide wraps across the date line instead of leaving the valid range.
+    assert_offset_seconds(&shifted, 18, 720 * 60);
+    assert_offset_seconds(&shifted, 36, 0);
+    // Smol Days remain pinned.
+    assert_offset_seconds(&shifted, 252, -12 * 3600);
+
+    assert!(shifted.verify_against_paper().is_err());
+    assert_eq!(
+        EquatorialSchedule::with_start_offset(720),
+        EquatorialSchedule::new()
+    );
+    assert_eq!(
+        EquatorialSchedule::with_start_offset(1440 + 720),
+        EquatorialSchedule::new()
+    );
+    assert_eq!(
+        EquatorialSchedule::with_start_offset(-720),
+        EquatorialSchedule::new()
+    );
+
+    // Extreme inputs wrap instead of overflowing.
+    for minutes in [i32::MAX, i32::MIN] {
+        let sched = EquatorialSchedule::with_start_offset(minutes);
+        assert!((-720..=720).contains(&sched.offset_minutes(0)));
+    }
+}
+
+#[test]
+fn offset_computation_never_panics_on_extreme_inputs() {
+    // Regression inputs from fuzzing `fuzz/fuzz_targets/schedule_offset.rs`.
+    for natural_tz in [0.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e300] {
+        let sched = HeuristicSchedule::new(2030, natural_tz);
+        for day in [0, 1 << 31, u32::MAX] {
+            let offset = sched.offset_minutes(day);
+            assert!(
+                (-720..=720).contains(&offset),
+                "{natural_tz} {day}: {offset}"
+            );
+            sched.get_timezone_offset(day);
+        }
+    }
+
+    let extreme = SmoitalConfig {
+        c2: 1e12,
+        ..SmoitalConfig::default()
+    };
+    assert!(HeuristicSchedule::with_config(2030, 0.0, extreme).is_err());
+
+    // Days past the end of the year roll into the repeating schedule.
+    let equatorial = EquatorialSchedule::new();
+    assert_eq!(
+        equatorial.offset_minutes(u32::MAX),
+        equatorial.offset_minutes(u32::MAX % equatorial.total_days())
+    );
+}
+
+#[test]
+fn equatorial_distinct_offsets_cover_formula_and_smol_day() {
+    let sched = EquatorialSchedule::new();
+    let offsets = sched.distinct_offsets(sched.total_days());
+
+    // 760 - 40 * D for D in 1..=36, plus the Smol Day's UTC-12:00.
+    assert_eq!(offsets.len(), 37);
+    assert_eq!(offsets[0].local_minus_utc(), -12 * 3600);
+    assert_eq!(offsets[36].local_minus_utc(), 12 * 3600);
+    assert!(
+        offsets
+            .windows(2)
+            .all(|w| w[0].local_minus_utc() < w[1].local_minus_utc())
+    );
+}
+
+#[test]
+fn civil_drift_grows_daily_and_drops_on_smol_days() {
+    let sched = EquatorialSchedule::new();
+    let standard_step = 24.0 * 3600.0 + 40.0 * 60.0 - SOL_LENGTH_SECONDS;
+    let smol_step = 24.0 * 3600.0 - SOL_LENGTH_SECONDS;
+
+    assert_eq!(sched.civil_vs_physical_drift(0), 0.0);
+    assert!((sched.civil_vs_physical_drift(1) - standard_step).abs() < 1e-6);
+
+    // Day 252 is the first Smol Day.
+    let before = sched.civil_vs_physical_drift(252);
+    assert!((before - 252.0 * standard_step).abs() < 1e-6);
+    assert!((sched.civil_vs_physical_drift(253) - (before + smol_step)).abs() < 1e-6);
+}
+
+#[test]
+fn posix_tz_approx_inverts_the_offset_sign() {
+    let sched = EquatorialSchedule::new();
+
+    // Day 0 is UTC+12:00, which POSIX writes with a negative sign.
+    assert_eq!(sched.posix_tz_approx(0), "MARS-12:00");
+    assert_eq!(sched.posix_tz_approx(1), "MARS-11:20");
+    // The Smol Day at UTC-12:00.
+    assert_eq!(sched.posix_tz_approx(252), "MARS12:00");
+}
+
+/// Slides 30 minutes a day instead of 40, with Smonth 0 long.
+struct GentleSlideSchedule;
+
+impl SmonthSchedule for GentleSlideSchedule {
+    fn is_smol_smonth(&self, smonth_index: u32) -> bool {
+        smonth_index == 0
+    }
+
+    fn raw_offset_minutes(&self, day_of_smonth: u32) -> i32 {
+        600 - 30 * (day_of_smonth as i32 - 1)
+    }
+}
+
+#[test]
+fn raw_offset_minutes_replaces_the_day_formula() {
+    let sched = GentleSlideSchedule;
+
+    assert_offset_seconds(&sched, 0, 600 * 60);
+    assert_offset_seconds(&sched, 10, 300 * 60);
+    // The Smol Day is still pinned by the default walk.
+    assert_offset_seconds(&sched, 36, -720 * 60);
+    // Smonth 1 restarts the custom formula.
+    assert_offset_seconds(&sched, 37, 600 * 60);
+
+    assert_eq!(EquatorialSchedule::new().raw_offset_minutes(1), 720);
+    assert_eq!(
+        EquatorialSchedule::with_start_offset(600).raw_offset_minutes(2),
+        560
+    );
+}
+
+#[test]
+fn boundary_offsets_report_first_and_last_day() {
+    let (first, last) = EquatorialSchedule::new().boundary_offsets();
+    assert_eq!(first.local_minus_utc(), 12 * 3600);
+    // Day 36 of the short final Smonth: 760 - 40 * 36 = -680 minutes.
+    assert_eq!(last.local_minus_utc(), -680 * 60);
+}
+
+#[test]
+fn recommended_next_smol_cancels_drift() {
+    let sched = EquatorialSchedule::new();
+    let standard_step = 24.0 * 3600.0 + 40.0 * 60.0 - SOL_LENGTH_SECONDS;
+    let smol_step = 24.0 * 3600.0 - SOL_LENGTH_SECONDS;
+
+    // After 36 standard days, roughly 60 more keep the clock within half a day's
+    // drift of physical time: one Smol Day per ~96 sols.
+    let drift = 36.0 * standard_step;
+    let next = sched.recommended_next_smol(drift).unwrap();
+    assert_eq!(next, 60);
+    let after = drift + next as f64 * standard_step + smol_step;
+    assert!(after.abs() <= standard_step / 2.0);
+
+    // Already a full correction behind: insert it today.
+    assert_eq!(sched.recommended_next_smol(-smol_step + 100.0), Some(0));
+    assert_eq!(sched.recommended_next_smol(f64::NAN), None);
+    assert_eq!(sched.recommended_next_smol(-1e9), None);
+}
+
+/// Mirrors the default slide: the offset climbs 40 minutes a day.
+struct EastwardSchedule;
+
+impl SmonthSchedule for EastwardSchedule {
+    fn is_smol_smonth(&self, _smonth_index: u32) -> bool {
+        false
+    }
+
+    fn raw_offset_minutes(&self, day_of_smonth: u32) -> i32 {
+        -680 + 40 * (day_of_smonth as i32 - 1)
+    }
+}
+
+#[test]
+fn slide_direction_follows_consecutive_offsets() {
+    assert_eq!(
+        EquatorialSchedule::new().slide_direction(),
+        SlideDirection::Westward
+    );
+    assert_eq!(
+        HeuristicSchedule::new(2030, 0.0).slide_direction(),
+        SlideDirection::Westward
+    );
+    assert_eq!(EastwardSchedule.slide_direction(), SlideDirection::Eastward);
+}
+
+#[test]
+fn smol_corrections_account_for_the_drift() {
+    let sched = EquatorialSchedule::new();
+
+    // Six Smol Days (252 through 545) fall before day 600.
+    assert_eq!(sched.total_smol_correction_minutes(600), 240);
+    assert_eq!(sched.total_smol_correction_minutes(252), 0);
+
+    // Over the year, drift is what standard days would accumulate minus the
+    // minutes the Smol Days take back.
+    let days = sched.total_days();
+    let correction = sched.total_smol_correction_minutes(days);
+    assert_eq!(correction, 7 * 40);
+    let uncorrected = days as f64 * (24.0 * 3600.0 + 40.0 * 60.0 - SOL_LENGTH_SECONDS);
+    let drift = sched.civil_vs_physical_drift(days);
+    assert!((uncorrected - correction as f64 * 60.0 - drift).abs() < 1e-6);
+}
+
+#[test]
+fn perihelion_sits_inside_the_long_smonth_cluster() {
+    let sched = EquatorialSchedule::new();
+    let perihelion = sched.perihelion_day().unwrap();
+
+    // Long Smonths 6 through 16 span days 216..619.
+    let cluster = sched.day_of_year(6, 1).unwrap()..sched.day_of_year(17, 1).unwrap();
+    assert_eq!(cluster, 216..619);
+    assert!(cluster.contains(&perihelion));
+    assert_eq!(perihelion, 399);
+
+    assert_eq!(EastwardSchedule.perihelion_day(), None);
+}
+
+#[test]
+fn polar_clusters_long_months_around_aphelion() {
+    let polar = PolarSchedule::new();
+    let equatorial = EquatorialSchedule::new();
+
+    let long: Vec<u32> = (0..18).filter(|&idx| polar.is_smol_smonth(idx)).collect();
+    assert_eq!(long, [1, 3, 4, 6, 7, 15, 17]);
+    assert!(long.iter().all(|&idx| polar.get_smonth_length(idx) == 37));
+    assert!(!polar.is_smol_smonth(18));
+    assert_eq!(polar.total_days(), equatorial.total_days());
+    assert_eq!(polar, PolarSchedule::default());
+
+    // Smol Days are still pinned at UTC-12:00, and the slide restarts after.
+    let smol_days: Vec<u32> = (0..polar.total_days())
+        .filter(|&day| polar.is_smol_day(day))
+        .collect();
+    assert_eq!(smol_days.len(), 7);
+    assert_eq!(smol_days[0], 36 + 36);
+    for &day in &smol_days {
+        assert_eq!(polar.offset_minutes(day), -720);
+        assert_eq!(polar.offset_minutes(day + 1), 720);
+    }
+    assert_eq!(polar.perihelion_day(), equatorial.perihelion_day());
+
+    let year = SmoitalYear::new(2030, polar);
+    assert_eq!(year.validate_all(), Ok(()));
+}
+
+#[test]
+fn custom_schedule_uses_supplied_long_indices() {
+    let sched = CustomSchedule::from_long_indices(&[17, 2, 9, 2]).unwrap();
+    assert_eq!(sched.long_indices(), [2, 9, 17]);
+
+    for idx in 0..18 {
+        let is_long = [2, 9, 17].contains(&idx);
+        assert_eq!(sched.is_smol_smonth(idx), is_long);
+        assert_eq!(sched.get_smonth_length(idx), if is_long { 37 } else { 36 });
+    }
+    assert_eq!(sched.total_days(), 18 * 36 + 3);
+
+    // Day 37 of Smonth 2 is a Smol Day pinned at UTC-12:00.
+    let smol_day = sched.day_of_year(2, 37).unwrap();
+    assert_eq!(
+        sched.get_timezone_offset(smol_day).local_minus_utc(),
+        -12 * 3600
+    );
+    assert_eq!(
+        sched.get_timezone_offset(smol_day + 1).local_minus_utc(),
+        12 * 3600
+    );
+    let last = sched.total_days() - 1;
+    assert_eq!(
+        sched.get_timezone_offset(last).local_minus_utc(),
+        -12 * 3600
+    );
+
+    assert_eq!(
+        CustomSchedule::from_long_indices(&[3, 18]),
+        Err(ScheduleError::SmonthOutOfRange(18))
+    );
+    assert_eq!(
+        CustomSchedule::from_long_indices(&[]),
+        Err(ScheduleError::NoSmolDays)
+    );
+}
+
+#[test]
+fn smol_month_count_counts_long_smonths() {
+    assert_eq!(EquatorialSchedule::new().smol_month_count(), 7);
+    assert_eq!(PolarSchedule::new().smol_month_count(), 7);
+    let custom = CustomSchedule::from_long_indices(&[1, 4, 4, 11]).unwrap();
+    assert_eq!(custom.smol_month_count(), 3);
+    assert_eq!(HeuristicSchedule::new(2090, 0.0).smol_month_count(), 6);
+}
+
+#[test]
+fn smol_days_are_exactly_the_utc_minus_twelve_days() {
+    let equatorial = EquatorialSchedule::new();
+    assert_eq!(equatorial.smol_days(), [252, 289, 362, 399, 472, 545, 618]);
+
+    let heuristic = HeuristicSchedule::new(2090, 0.0);
+    assert!(!heuristic.smol_days().is_empty());
+
+    let schedules: [&dyn SmonthSchedule; 3] = [&equatorial, &PolarSchedule::new(), &heuristic];
+    for schedule in schedules {
+        let smol_days = schedule.smol_days();
+        for day in 0..schedule.total_days() {
+            let pinned = schedule.get_timezone_offset(day).local_minus_utc() == -12 * 3600;
+            assert_eq!(smol_days.contains(&day), pinned, "day {day}");
+        }
+    }
 }
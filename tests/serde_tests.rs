@@ -0,0 +1,67 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use smoital::date::SmoitalDate;
+
+#[test]
+fn dates_serialize_as_structs() {
+    let date = SmoitalDate {
+        year: 2030,
+        smonth: 6,
+        day: 37,
+    };
+
+    let json = serde_json::to_string(&date).unwrap();
+    assert_eq!(json, r#"{"year":2030,"smonth":6,"day":37}"#);
+    assert_eq!(serde_json::from_str::<SmoitalDate>(&json).unwrap(), date);
+
+    for bad in [
+        r#"{"year":2030,"smonth":6,"day":0}"#,
+        r#"{"year":2030,"smonth":6,"day":38}"#,
+        r#"{"year":2030,"smonth":18,"day":1}"#,
+    ] {
+        assert!(serde_json::from_str::<SmoitalDate>(bad).is_err(), "{bad}");
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Event {
+    #[serde(with = "smoital::date::serde_str")]
+    date: SmoitalDate,
+}
+
+#[test]
+fn dates_serialize_as_strings_on_request() {
+    let event = Event {
+        date: SmoitalDate {
+            year: -12,
+            smonth: 0,
+            day: 1,
+        },
+    };
+
+    let json = serde_json::to_string(&event).unwrap();
+    assert_eq!(json, r#"{"date":"-0012-S00-01"}"#);
+    assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+    assert!(serde_json::from_str::<Event>(r#"{"date":"2030-S06-00"}"#).is_err());
+}
+
+#[test]
+fn string_dates_deserialize_from_owned_input() {
+    let expected = Event {
+        date: SmoitalDate {
+            year: 2030,
+            smonth: 6,
+            day: 37,
+        },
+    };
+
+    let value = serde_json::json!({ "date": "2030-S06-37" });
+    assert_eq!(serde_json::from_value::<Event>(value).unwrap(), expected);
+
+    let reader = br#"{"date":"2030-S06-37"}"#.as_slice();
+    assert_eq!(
+        serde_json::from_reader::<_, Event>(reader).unwrap(),
+        expected
+    );
+}
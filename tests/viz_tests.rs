@@ -0,0 +1,34 @@
+#![cfg(feature = "viz")]
+
+use smoital::schedule::EquatorialSchedule;
+use smoital::year::SmoitalYear;
+
+#[test]
+fn svg_calendar_has_a_cell_per_day_and_smol_markers() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    let svg = year.to_svg();
+
+    let doc = roxmltree::Document::parse(&svg).expect("valid XML");
+    let root = doc.root_element();
+    assert_eq!(root.tag_name().name(), "svg");
+    assert_eq!(
+        root.tag_name().namespace(),
+        Some("http://www.w3.org/2000/svg")
+    );
+
+    let count = |name: &str| {
+        doc.descendants()
+            .filter(|n| n.tag_name().name() == name)
+            .count()
+    };
+    assert_eq!(count("rect"), 655);
+    assert_eq!(count("text"), 18);
+
+    let smol_markers = doc
+        .descendants()
+        .filter(|n| n.has_tag_name("circle") && n.attribute("class") == Some("smol"))
+        .count();
+    assert_eq!(smol_markers, 7);
+
+    assert!(svg.contains("<title>2090-S06-37 UTC-12:00</title>"));
+}
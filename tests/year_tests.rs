@@ -1,5 +1,10 @@
+use chrono::{Datelike, FixedOffset, NaiveDate, Weekday};
+use smoital::constants::{MARS_YEAR_SOLS, SMOITAL_EPOCH, SMOITAL_EPOCH_YEAR, SOL_LENGTH_SECONDS};
 use smoital::date::SmoitalDate;
-use smoital::schedule::EquatorialSchedule;
+use smoital::duration::MarsDuration;
+use smoital::schedule::{
+    CustomSchedule, EquatorialSchedule, HeuristicSchedule, PolarSchedule, SmonthSchedule,
+};
 use smoital::year::SmoitalYear;
 
 #[test]
@@ -70,3 +75,609 @@ fn timezone_offsets_can_be_queried_with_dates() {
     };
     assert!(year.timezone_offset_for_date(&wrong_year).is_none());
 }
+
+#[test]
+fn offset_rle_decodes_to_daily_offsets() {
+    let year = SmoitalYear::new(2030, EquatorialSchedule::new());
+    let runs = year.offset_rle();
+
+    let decoded: Vec<_> = runs
+        .iter()
+        .flat_map(|&(offset, len)| std::iter::repeat_n(offset, len as usize))
+        .collect();
+    let daily: Vec<_> = (0..year.total_days())
+        .map(|day| year.timezone_offset_for_day(day))
+        .collect();
+
+    assert_eq!(decoded, daily);
+    assert!(runs.windows(2).all(|pair| pair[0].0 != pair[1].0));
+}
+
+#[test]
+fn total_earth_seconds_counts_physical_sols() {
+    let year = SmoitalYear::new(2030, EquatorialSchedule::new());
+    let expected = year.total_days() as f64 * SOL_LENGTH_SECONDS;
+    assert!((year.total_earth_seconds() - expected).abs() < 1e-6);
+
+    // The 18-Smonth calendar year covers 655 of the Mars year's 668.5991 sols,
+    // falling 13.5991 sols (~13.97 Earth days) short of the orbital period.
+    let mars_year_earth_seconds = MARS_YEAR_SOLS * SOL_LENGTH_SECONDS;
+    let shortfall_sols =
+        (mars_year_earth_seconds - year.total_earth_seconds()) / SOL_LENGTH_SECONDS;
+    assert!((shortfall_sols - 13.5991).abs() < 1e-9, "{shortfall_sols}");
+}
+
+#[test]
+fn transitions_cover_every_offset_change() {
+    let year = SmoitalYear::new(2030, EquatorialSchedule::new());
+
+    // The Equatorial clock shifts every single day.
+    assert_eq!(
+        year.iter_transitions().count(),
+        year.offset_transition_count()
+    );
+    assert_eq!(
+        year.offset_transition_count(),
+        year.total_days() as usize - 1
+    );
+
+    let (day, offset) = year.offset_transitions()[251];
+    assert_eq!(day, 252);
+    assert_eq!(offset.local_minus_utc(), -12 * 3600);
+}
+
+#[test]
+fn duration_between_counts_physical_sols() {
+    let year = SmoitalYear::new(2030, EquatorialSchedule::new());
+    let start = year.date_from_day(0);
+    let later = year.date_from_day(10);
+
+    let gap = year.duration_between(&start, &later).unwrap();
+    let expected = MarsDuration::from_sols(10.0);
+    assert!((gap.as_mars_seconds() - expected.as_mars_seconds()).abs() < 1e-6);
+    assert!((gap.as_earth_seconds() - 10.0 * SOL_LENGTH_SECONDS).abs() < 1e-6);
+
+    let back = year.duration_between(&later, &start).unwrap();
+    assert!((back.as_sols() + 10.0).abs() < 1e-9);
+
+    let invalid = SmoitalDate {
+        year: 2030,
+        smonth: 0,
+        day: 37,
+    };
+    assert!(year.duration_between(&start, &invalid).is_none());
+}
+
+#[test]
+fn smonth_of_day_locates_smol_day() {
+    let schedule = EquatorialSchedule::new();
+    assert_eq!(schedule.smonth_of_day(0), Some((0, 1)));
+    assert_eq!(schedule.smonth_of_day(252), Some((6, 37)));
+    assert_eq!(schedule.smonth_of_day(253), Some((7, 1)));
+    assert_eq!(schedule.smonth_of_day(schedule.total_days()), None);
+
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    assert_eq!(year.try_date_from_day(year.total_days()), None);
+    assert_eq!(year.try_date_from_day(252), Some(year.date_from_day(252)));
+
+    // The unchecked conversion rolls past the end into the following years.
+    let next = |smonth, day| SmoitalDate {
+        year: 2091,
+        smonth,
+        day,
+    };
+    assert_eq!(year.date_from_day(year.total_days()), next(0, 1));
+    assert_eq!(year.date_from_day(year.total_days() + 252), next(6, 37));
+    assert_eq!(
+        year.date_from_day(u32::MAX).year,
+        2090 + (u32::MAX / 655) as i32
+    );
+}
+
+#[test]
+fn gregorian_crosswalk_skips_a_date_every_smonth() {
+    let year = SmoitalYear::new(2030, EquatorialSchedule::new());
+    let epoch = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+    let table = year.gregorian_crosswalk(epoch);
+
+    assert_eq!(table.len(), year.total_days() as usize);
+    assert_eq!(
+        table[0],
+        (
+            SmoitalDate {
+                year: 2030,
+                smonth: 0,
+                day: 1
+            },
+            epoch
+        )
+    );
+
+    // One Earth day per sol until the accumulated 2.7% surplus skips a date.
+    for pair in table[..37].windows(2) {
+        assert_eq!((pair[1].1 - pair[0].1).num_days(), 1);
+    }
+    assert_eq!((table[37].1 - table[36].1).num_days(), 2);
+
+    let smol = SmoitalDate {
+        year: 2030,
+        smonth: 6,
+        day: 37,
+    };
+    assert_eq!(year.to_gregorian(&smol, epoch), Some(table[252].1));
+}
+
+#[test]
+fn gregorian_weekday_follows_the_epoch() {
+    let year = SmoitalYear::new(2030, EquatorialSchedule::new());
+    // 2030-01-01 is a Tuesday.
+    let epoch = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+
+    assert_eq!(year.gregorian_weekday(0, epoch), Some(epoch.weekday()));
+    assert_eq!(year.gregorian_weekday(0, epoch), Some(Weekday::Tue));
+    // Day 37 skips a Gregorian date: 2030-02-08, a Friday.
+    assert_eq!(year.gregorian_weekday(37, epoch), Some(Weekday::Fri));
+    assert_eq!(year.gregorian_weekday(year.total_days(), epoch), None);
+}
+
+#[test]
+fn offset_at_validates_time_of_day() {
+    let year = SmoitalYear::new(2030, EquatorialSchedule::new());
+    let twenty_four_ten = (24 * 60 + 10) as f64 * 60.0;
+
+    // 24:10 exists on a standard day but not on the 24-hour Smol Day.
+    assert_eq!(
+        year.offset_at(251, twenty_four_ten),
+        Some(year.timezone_offset_for_day(251))
+    );
+    assert_eq!(year.offset_at(252, twenty_four_ten), None);
+    assert_eq!(
+        year.offset_at(252, 3600.0).map(|o| o.local_minus_utc()),
+        Some(-12 * 3600)
+    );
+
+    assert_eq!(year.offset_at(0, -1.0), None);
+    assert_eq!(year.offset_at(year.total_days(), 0.0), None);
+}
+
+#[test]
+fn years_can_be_cloned_and_compared() {
+    let year = SmoitalYear::new(2030, EquatorialSchedule::new());
+    let cached = year.clone();
+    assert_eq!(cached, year);
+    assert_ne!(SmoitalYear::new(2031, EquatorialSchedule::new()), year);
+
+    let heuristic = SmoitalYear::new(2030, HeuristicSchedule::new(2030, 0.0));
+    assert_eq!(heuristic.clone(), heuristic);
+}
+
+#[test]
+fn days_with_offset_finds_each_recurrence() {
+    let year = SmoitalYear::new(2030, EquatorialSchedule::new());
+
+    // The clock sits at UTC on day 19 of every Smonth.
+    let utc_days = year.days_with_offset(FixedOffset::east_opt(0).unwrap());
+    assert_eq!(utc_days.len(), 18);
+    assert_eq!(utc_days[0], 18);
+    assert!(utc_days.iter().all(|&d| year.date_from_day(d).day == 19));
+
+    // -12:00 only happens on the seven Smol Days.
+    let smol_days = year.days_with_offset(FixedOffset::west_opt(12 * 3600).unwrap());
+    assert_eq!(smol_days.len(), 7);
+    assert_eq!(smol_days[0], 252);
+}
+
+#[test]
+fn add_smonths_clamps_to_shorter_smonths() {
+    let year = SmoitalYear::new(2030, EquatorialSchedule::new());
+    let smol = SmoitalDate {
+        year: 2030,
+        smonth: 6,
+        day: 37,
+    };
+
+    // Smonth 7 is also long, so day 37 survives.
+    let next = year.add_smonths(&smol, 1).unwrap();
+    assert_eq!((next.smonth, next.day), (7, 37));
+
+    // Smonth 8 is a standard 36-day Smonth.
+    let clamped = year.add_smonths(&smol, 2).unwrap();
+    assert_eq!((clamped.smonth, clamped.day), (8, 36));
+
+    let back = year.add_smonths(&smol, -6).unwrap();
+    assert_eq!((back.smonth, back.day), (0, 36));
+
+    assert_eq!(year.add_smonths(&smol, 12), None);
+    assert_eq!(year.add_smonths(&smol, -7), None);
+}
+
+#[test]
+fn offsets_fixed_matches_daily_offsets() {
+    let year = SmoitalYear::new(2030, EquatorialSchedule::new());
+
+    // 18 Smonths with seven long ones: 18 * 36 + 7 = 655 days.
+    let table: [FixedOffset; 655] = year.offsets_fixed().unwrap();
+    let daily: Vec<_> = (0..year.total_days())
+        .map(|day| year.timezone_offset_for_day(day))
+        .collect();
+    assert_eq!(table.to_vec(), daily);
+
+    assert!(year.offsets_fixed::<668>().is_none());
+}
+
+/// Places Smonth 2 one day later than its neighbours think it starts.
+struct MisplacedSmonthSchedule;
+
+impl SmonthSchedule for MisplacedSmonthSchedule {
+    fn is_smol_smonth(&self, _smonth_index: u32) -> bool {
+        false
+    }
+
+    fn day_of_year(&self, smonth: u32, day: u32) -> Option<u32> {
+        if smonth >= self.num_smonths() || day == 0 || day > 36 {
+            return None;
+        }
+        Some(smonth * 36 + day - 1 + u32::from(smonth == 2))
+    }
+}
+
+/// Pins the heuristic Smol Days by offset alone, without lengthening any Smonth.
+struct OffsetPinnedSchedule;
+
+impl SmonthSchedule for OffsetPinnedSchedule {
+    fn is_smol_smonth(&self, _smonth_index: u32) -> bool {
+        false
+    }
+
+    fn offset_minutes(&self, day_of_year: u32) -> i32 {
+        if [216, 253, 326, 363, 436, 509].contains(&day_of_year) {
+            -720
+        } else {
+            0
+        }
+    }
+}
+
+#[test]
+fn validate_all_reports_non_round_tripping_dates() {
+    assert_eq!(
+        SmoitalYear::new(2090, EquatorialSchedule::new()).validate_all(),
+        Ok(())
+    );
+
+    let broken = SmoitalYear::new(2090, MisplacedSmonthSchedule).validate_all();
+    let expected: Vec<SmoitalDate> = (1..=36)
+        .map(|day| SmoitalDate {
+            year: 2090,
+            smonth: 2,
+            day,
+        })
+        .collect();
+    assert_eq!(broken, Err(expected));
+
+    // Smol Days pinned by offset in a year of 36-day Smonths land mid-Smonth.
+    let pinned = SmoitalYear::new(2090, OffsetPinnedSchedule);
+    let misplaced: Vec<SmoitalDate> = [216, 253, 326, 363, 436, 509]
+        .into_iter()
+        .map(|day| pinned.date_from_day(day))
+        .collect();
+    assert_eq!(misplaced[0].smonth, 6);
+    assert_eq!(misplaced[0].day, 1);
+    assert_eq!(pinned.validate_all(), Err(misplaced));
+
+    // The heuristic schedule lengthens the Smonths its Smol Days close.
+    let heuristic = SmoitalYear::new(2090, HeuristicSchedule::new(2090, 0.0));
+    assert_eq!(heuristic.validate_all(), Ok(()));
+}
+
+#[test]
+fn add_days_rolls_across_smonths_and_years() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    let date = |year, smonth, day| SmoitalDate { year, smonth, day };
+
+    // Smonth 6 is long: its 37th day (the Smol Day) sits between 36 and Smonth 7.
+    assert_eq!(
+        year.add_days(&date(2090, 6, 30), 7),
+        Some(date(2090, 6, 37))
+    );
+    assert_eq!(year.add_days(&date(2090, 6, 30), 8), Some(date(2090, 7, 1)));
+    assert_eq!(
+        year.add_days(&date(2090, 7, 1), -1),
+        Some(date(2090, 6, 37))
+    );
+
+    // Crossing the year boundary in both directions (655 days per year).
+    assert_eq!(
+        year.add_days(&date(2090, 17, 36), 1),
+        Some(date(2091, 0, 1))
+    );
+    assert_eq!(
+        year.add_days(&date(2090, 0, 1), -1),
+        Some(date(2089, 17, 36))
+    );
+    assert_eq!(
+        year.add_days(&date(2090, 0, 1), 655 * 2),
+        Some(date(2092, 0, 1))
+    );
+
+    assert_eq!(year.add_days(&date(2091, 0, 1), 1), None);
+}
+
+#[test]
+fn days_between_counts_the_37th_day() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    let date = |smonth, day| SmoitalDate {
+        year: 2090,
+        smonth,
+        day,
+    };
+
+    // Smonth 6 has 37 days, Smonth 5 only 36.
+    assert_eq!(year.days_between(&date(6, 30), &date(7, 1)), Some(8));
+    assert_eq!(year.days_between(&date(5, 30), &date(6, 1)), Some(7));
+    assert_eq!(year.days_between(&date(7, 1), &date(6, 30)), Some(-8));
+    assert_eq!(year.days_between(&date(3, 3), &date(3, 3)), Some(0));
+
+    assert_eq!(year.days_between(&date(5, 37), &date(6, 1)), None);
+    let other_year = SmoitalDate {
+        year: 2091,
+        smonth: 0,
+        day: 1,
+    };
+    assert_eq!(year.days_between(&date(0, 1), &other_year), None);
+}
+
+#[test]
+fn gregorian_dates_anchor_on_the_epoch() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(SMOITAL_EPOCH, ymd(1955, 4, 11));
+
+    let first = SmoitalYear::new(SMOITAL_EPOCH_YEAR, EquatorialSchedule::new());
+    let date = |year, smonth, day| SmoitalDate { year, smonth, day };
+
+    assert_eq!(first.year_start_gregorian(), Some(SMOITAL_EPOCH));
+    assert_eq!(
+        first.to_gregorian_anchored(&date(1, 0, 1)),
+        Some(SMOITAL_EPOCH)
+    );
+    // Day 36 lands on 1955-05-17; day 37 skips the 18th.
+    assert_eq!(
+        first.to_gregorian_anchored(&date(1, 1, 1)),
+        Some(ymd(1955, 5, 17))
+    );
+    assert_eq!(
+        first.to_gregorian_anchored(&date(1, 1, 2)),
+        Some(ymd(1955, 5, 19))
+    );
+
+    // Years follow on directly, 655 sols (673.007 Earth days) apart.
+    let second = SmoitalYear::new(2, EquatorialSchedule::new());
+    assert_eq!(second.year_start_gregorian(), Some(ymd(1957, 2, 12)));
+    let my38 = SmoitalYear::new(38, EquatorialSchedule::new());
+    assert_eq!(my38.year_start_gregorian(), Some(ymd(2023, 6, 14)));
+    let zeroth = SmoitalYear::new(0, EquatorialSchedule::new());
+    assert_eq!(zeroth.year_start_gregorian(), Some(ymd(1953, 6, 6)));
+
+    // Agrees with the explicit-epoch conversion.
+    let d = date(2, 6, 37);
+    assert_eq!(
+        second.to_gregorian_anchored(&d),
+        second.to_gregorian(&d, second.year_start_gregorian().unwrap())
+    );
+    assert_eq!(first.to_gregorian_anchored(&date(2, 0, 1)), None);
+}
+
+#[test]
+fn from_gregorian_inverts_to_gregorian() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    let epoch = NaiveDate::from_ymd_opt(2090, 1, 1).unwrap();
+
+    // Every day of the year round-trips, including all Smol Days.
+    for day in 0..year.total_days() {
+        let date = year.date_from_day(day);
+        let earth = year.to_gregorian(&date, epoch).unwrap();
+        assert_eq!(year.from_gregorian(earth, epoch), Some(date), "day {day}");
+    }
+    let smol = SmoitalDate {
+        year: 2090,
+        smonth: 6,
+        day: 37,
+    };
+    let earth = year.to_gregorian(&smol, epoch).unwrap();
+    assert_eq!(year.from_gregorian(earth, epoch), Some(smol));
+
+    // Day 36 starts on Feb 6 and day 37 on Feb 8; Feb 7 falls inside day 36.
+    let skipped = NaiveDate::from_ymd_opt(2090, 2, 7).unwrap();
+    assert_eq!(
+        year.from_gregorian(skipped, epoch),
+        Some(year.date_from_day(36))
+    );
+
+    assert_eq!(year.from_gregorian(epoch.pred_opt().unwrap(), epoch), None);
+    // Past the end of the year, dates roll into the next one.
+    let past_end = epoch + chrono::Days::new(700);
+    assert_eq!(
+        year.from_gregorian(past_end, epoch),
+        Some(year.date_from_day(682))
+    );
+}
+
+#[test]
+fn anchored_conversion_has_no_gap_between_years() {
+    let first = NaiveDate::from_ymd_opt(2023, 5, 1).unwrap();
+    let year = SmoitalYear::new(38, EquatorialSchedule::new());
+    let start = year.year_start_gregorian().unwrap();
+
+    // Walk Gregorian dates from before year 38 begins to after it ends.
+    let mut previous: Option<SmoitalDate> = None;
+    for offset in 0..(start - first).num_days() + 720 {
+        let earth = first + chrono::Days::new(offset as u64);
+        let date = year.from_gregorian_anchored(earth).unwrap();
+
+        // The date maps back to the same Gregorian date, or to the day
+        // before it if `earth` is a Skipped Date.
+        let owner = SmoitalYear::new(date.year, EquatorialSchedule::new());
+        let back = owner.to_gregorian_anchored(&date).unwrap();
+        assert!(
+            back == earth || back.succ_opt() == Some(earth),
+            "{earth}: {date}"
+        );
+
+        // Consecutive dates never skip a Smoital day, even across years.
+        if let Some(previous) = previous {
+            let next =
+                SmoitalYear::new(previous.year, EquatorialSchedule::new()).add_days(&previous, 1);
+            assert!(
+                date == previous || next.as_ref() == Some(&date),
+                "{earth}: {previous} -> {date}"
+            );
+        }
+        previous = Some(date);
+    }
+
+    // Year 37 ends on the last sol before year 38's first.
+    let before = SmoitalYear::new(37, EquatorialSchedule::new());
+    let last = before.date_from_day(before.total_days() - 1);
+    let first_of_38 = year.date_from_day(0);
+    assert_eq!(year.to_gregorian_anchored(&first_of_38), Some(start));
+    assert_eq!(before.add_days(&last, 1), Some(first_of_38));
+    assert!(before.to_gregorian_anchored(&last).unwrap() <= start);
+}
+
+#[test]
+fn dates_walks_the_whole_year() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    let dates = year.dates();
+    assert_eq!(dates.len(), 655);
+
+    let all: Vec<SmoitalDate> = dates.collect();
+    assert_eq!(all.len(), year.total_days() as usize);
+    assert_eq!(all[0], SmoitalDate::new(2090, 0, 1).unwrap());
+    assert_eq!(all[252], SmoitalDate::new(2090, 6, 37).unwrap());
+    assert_eq!(all.last(), Some(&SmoitalDate::new(2090, 17, 36).unwrap()));
+    assert!(all.windows(2).all(|w| w[0] < w[1]));
+
+    let mut dates = year.dates();
+    dates.nth(653);
+    assert_eq!(dates.len(), 1);
+}
+
+#[test]
+fn finds_neighbouring_smol_days() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    let smol_days = [252, 289, 362, 399, 472, 545, 618];
+
+    assert_eq!(year.next_smol_day(0), Some(252));
+    for pair in smol_days.windows(2) {
+        assert_eq!(year.next_smol_day(pair[0]), Some(pair[1]));
+        assert_eq!(year.prev_smol_day(pair[1]), Some(pair[0]));
+        assert_eq!(year.next_smol_day(pair[0] + 1), Some(pair[1]));
+    }
+    assert_eq!(year.next_smol_day(618), None);
+    assert_eq!(year.prev_smol_day(252), None);
+    assert_eq!(year.prev_smol_day(u32::MAX), Some(618));
+
+    let heuristic = SmoitalYear::new(2090, HeuristicSchedule::new(2090, 0.0));
+    let heuristic_smol_days = [216, 253, 326, 363, 436, 509];
+
+    assert_eq!(heuristic.next_smol_day(0), Some(216));
+    for pair in heuristic_smol_days.windows(2) {
+        assert_eq!(heuristic.next_smol_day(pair[0]), Some(pair[1]));
+        assert_eq!(heuristic.prev_smol_day(pair[1]), Some(pair[0]));
+        assert_eq!(heuristic.prev_smol_day(pair[1] - 1), Some(pair[0]));
+    }
+    assert_eq!(heuristic.next_smol_day(509), None);
+    assert_eq!(heuristic.prev_smol_day(216), None);
+    assert_eq!(heuristic.prev_smol_day(u32::MAX), Some(509));
+}
+
+#[test]
+fn gregorian_span_covers_the_year_in_earth_days() {
+    let year = SmoitalYear::new(2, EquatorialSchedule::new());
+    let epoch = year.year_start_gregorian().unwrap();
+    let (first, last) = year.gregorian_span(epoch).unwrap();
+
+    // 655 sols last 673.006 Earth days, touching 674 dates.
+    assert_eq!(first, NaiveDate::from_ymd_opt(1957, 2, 12).unwrap());
+    let span_days = (last - first).num_days() + 1;
+    assert_eq!(
+        span_days,
+        (year.total_earth_seconds() / 86_400.0).ceil() as i64
+    );
+    assert_eq!(span_days, 674);
+
+    // The next year starts on the date this one's last sol ends.
+    let next = SmoitalYear::new(3, EquatorialSchedule::new());
+    assert_eq!(next.year_start_gregorian(), Some(last));
+}
+
+#[test]
+fn smol_countdown_names_the_next_reset() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+
+    assert_eq!(
+        year.format_smol_countdown(250),
+        "Next clock reset in 2 sols (2090-S06-37)"
+    );
+    assert_eq!(
+        year.format_smol_countdown(251),
+        "Next clock reset in 1 sol (2090-S06-37)"
+    );
+    assert_eq!(year.format_smol_countdown(252), "Clock reset today");
+    assert_eq!(
+        year.format_smol_countdown(253),
+        "Next clock reset in 36 sols (2090-S07-37)"
+    );
+    assert_eq!(
+        year.format_smol_countdown(620),
+        "No clock reset left this year"
+    );
+}
+
+#[test]
+fn civil_duration_between_shortens_for_smol_days() {
+    let year = SmoitalYear::new(2090, EquatorialSchedule::new());
+    let date = |smonth, day| SmoitalDate::new(2090, smonth, day).unwrap();
+    let standard = (24.0 * 60.0 + 40.0) * 60.0;
+    let smol = 24.0 * 3600.0;
+
+    // Three days without a Smol Day, then three days including 2090-S06-37.
+    let plain = year
+        .civil_duration_between(&date(6, 1), &date(6, 4))
+        .unwrap();
+    assert!((plain.as_earth_seconds() - 3.0 * standard).abs() < 1e-6);
+
+    let with_smol = year
+        .civil_duration_between(&date(6, 36), &date(7, 2))
+        .unwrap();
+    assert!((with_smol.as_earth_seconds() - (2.0 * standard + smol)).abs() < 1e-6);
+    let back = year
+        .civil_duration_between(&date(7, 2), &date(6, 36))
+        .unwrap();
+    assert!((back.as_earth_seconds() + with_smol.as_earth_seconds()).abs() < 1e-6);
+
+    // Physical time ignores the Smol Day.
+    let physical = year.duration_between(&date(6, 36), &date(7, 2)).unwrap();
+    assert!((physical.as_sols() - 3.0).abs() < 1e-9);
+
+    assert_eq!(year.civil_duration_between(&date(5, 37), &date(6, 1)), None);
+}
+
+#[test]
+fn total_days_matches_the_dates_in_the_year() {
+    fn dated_days<S: SmonthSchedule>(schedule: S) -> (u32, u32) {
+        let total = schedule.total_days();
+        let year = SmoitalYear::new(2090, schedule);
+        let dated = (0..)
+            .take_while(|&d| year.try_date_from_day(d).is_some())
+            .count();
+        (total, dated as u32)
+    }
+
+    // 18 Smonths of 36 days plus seven long ones: 655, not the 668 sols of a
+    // Mars year, which the calendar year does not fill.
+    assert_eq!(dated_days(EquatorialSchedule::new()), (655, 655));
+    assert_eq!(dated_days(PolarSchedule::new()), (655, 655));
+    let custom = CustomSchedule::from_long_indices(&[0, 5]).unwrap();
+    assert_eq!(dated_days(custom), (650, 650));
+}